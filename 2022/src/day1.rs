@@ -0,0 +1,27 @@
+use tracing::{info_span, instrument};
+
+use aoc_utils::answer::Answer;
+
+pub const TITLE: &str = "Day 1";
+pub const INPUT: &str = include_str!("../inputs/day1.txt");
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let mut input = input
+        .split("\n\n")
+        .map(|inv| inv.lines().map(|cnt| cnt.parse::<u64>().unwrap()).sum())
+        .collect::<Vec<_>>();
+    span.exit();
+    [part1(&input).into(), part2(&mut input).into()]
+}
+
+#[instrument(skip_all)]
+fn part1(input: &[u64]) -> u64 {
+    *input.iter().max().unwrap()
+}
+
+#[instrument(skip_all)]
+fn part2(input: &mut [u64]) -> u64 {
+    input.sort_unstable();
+    input.iter().rev().take(3).sum()
+}
@@ -0,0 +1,170 @@
+use std::{
+    io::{self, Write},
+    thread,
+};
+
+use serde::Deserialize;
+use tracing::{info_span, instrument};
+
+use aoc_utils::{
+    answer::{Answer, Image},
+    config, frames,
+    grid::Grid,
+    point::Point,
+};
+
+enum Intruction {
+    Addx(i32),
+    Noop,
+}
+
+pub const TITLE: &str = "Day 10";
+pub const INPUT: &str = include_str!("../inputs/day10.txt");
+
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Params {
+    /// The cycles during which the signal strength is sampled in part 1
+    cycles: Vec<usize>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            cycles: vec![20, 60, 100, 140, 180, 220],
+        }
+    }
+}
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let input = parse(input);
+    span.exit();
+    let params: Params = config::get().params("day10");
+    [part1(&input, &params.cycles).into(), part2(&input).into()]
+}
+
+fn parse(input: &str) -> Vec<Intruction> {
+    input
+        .lines()
+        .map(|line| match &line[..4] {
+            "addx" => Intruction::Addx(line[5..].parse().unwrap()),
+            "noop" => Intruction::Noop,
+            other => panic!("invalid instruction `{other}`"),
+        })
+        .collect()
+}
+
+#[instrument(skip_all)]
+fn part1(input: &[Intruction], cycles: &[usize]) -> usize {
+    let mut x: i32 = 1;
+    let mut cycle = 0;
+    let mut total_signal_strength = 0;
+
+    for instruction in input {
+        match instruction {
+            Intruction::Addx(num) => {
+                part1_next_cycle(&mut cycle, x, &mut total_signal_strength, cycles);
+                part1_next_cycle(&mut cycle, x, &mut total_signal_strength, cycles);
+                x += num;
+            }
+            Intruction::Noop => {
+                part1_next_cycle(&mut cycle, x, &mut total_signal_strength, cycles);
+            }
+        }
+    }
+
+    total_signal_strength
+}
+
+fn part1_next_cycle(
+    cycle: &mut usize,
+    x: i32,
+    total_signal_strength: &mut usize,
+    cycles: &[usize],
+) {
+    *cycle += 1;
+    if cycles.contains(cycle) {
+        *total_signal_strength += *cycle * x as usize
+    }
+}
+
+#[instrument(skip_all)]
+fn part2(input: &[Intruction]) -> Image {
+    let mut crt = Grid::new(40, 6, false);
+    let mut x: i32 = 1;
+    let mut cycle = 0;
+
+    for instruction in input {
+        match instruction {
+            Intruction::Addx(num) => {
+                part2_next_cycle(&mut cycle, x, &mut crt);
+                part2_next_cycle(&mut cycle, x, &mut crt);
+                x += num;
+            }
+            Intruction::Noop => {
+                part2_next_cycle(&mut cycle, x, &mut crt);
+            }
+        }
+    }
+
+    crt.into()
+}
+
+/// Draws the pixel of `cycle`, adding a row to the CRT for programs longer than 240 cycles.
+fn part2_next_cycle(cycle: &mut usize, x: i32, crt: &mut Grid<bool>) {
+    let pixel = Point::new((*cycle % 40) as i64, (*cycle / 40) as i64);
+    if pixel.y == crt.height() as i64 {
+        crt.grow([0, 0, 0, 1], false);
+    }
+    crt[pixel] = (x - 1..=x + 1).contains(&(pixel.x as i32));
+    *cycle += 1;
+    if frames::watching() {
+        frames::send(LEGEND, |out| render_crt(out, crt, pixel));
+        thread::sleep(config::get().frame_delay());
+    }
+}
+
+/// The colors of the glyphs of [`render_crt`] on the dashboard.
+const LEGEND: &[(char, &str)] = &[('@', "#fbc02d"), ('#', "#43a047"), ('.', "#263238")];
+
+/// Draws the pixels of the CRT so far, marking the one at `beam` that was just drawn.
+fn render_crt(out: &mut impl Write, crt: &Grid<bool>, beam: Point) -> io::Result<()> {
+    for (y, row) in crt.rows().enumerate() {
+        for (x, lit) in row.iter().enumerate() {
+            let glyph = match (Point::new(x as i64, y as i64) == beam, lit) {
+                (true, _) => '@',
+                (false, true) => '#',
+                (false, false) => '.',
+            };
+            write!(out, "{glyph}")?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_renders;
+
+    #[test]
+    fn crt() {
+        let crt = part2(&parse(INPUT));
+        assert_eq!(crt.ocr().as_deref(), Some("FJUBULRZ"));
+        assert_renders("day10_crt", |color| crt.render(color));
+    }
+
+    #[test]
+    fn crt_grows_for_long_programs() {
+        let crt = part2(&parse(&"noop\n".repeat(241)));
+        assert_eq!(crt.render(false).lines().count(), 7);
+    }
+
+    #[test]
+    fn crt_survives_json() {
+        let answer = Answer::from(part2(&parse(INPUT)));
+        assert_eq!(Answer::from_json(&answer.to_json()), Some(answer));
+    }
+}
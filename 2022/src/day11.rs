@@ -2,24 +2,26 @@ use std::mem;
 
 use itertools::Itertools;
 use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::digit1,
-    combinator::{map_res, opt},
-    multi::separated_list1,
-    IResult,
+    branch::alt, bytes::complete::tag, combinator::opt, error::context, multi::separated_list1,
 };
 use num_integer::Integer;
+use tracing::{debug, info_span, instrument};
+
+use aoc_utils::{
+    answer::Answer,
+    cancel,
+    parse::{self, blocks, unsigned, IResult},
+};
 
 #[derive(Debug)]
-struct Monkey {
-    number: usize,
+pub struct Monkey {
+    pub number: usize,
     starting_items: Vec<usize>,
     operator: Operator,
     operand: Value,
     divisible_by: usize,
-    if_true: usize,
-    if_false: usize,
+    pub if_true: usize,
+    pub if_false: usize,
 }
 
 #[derive(Debug)]
@@ -36,41 +38,38 @@ enum Value {
 
 ///////////////////////////////////////////
 
-fn monkey(input: &str) -> IResult<&str, Monkey> {
-    let (input, _) = tag("Monkey ")(input)?;
-    let (input, number) = usize(input)?;
-    let (input, _) = tag(":\n  Starting items: ")(input)?;
-    let (input, starting_items) = separated_list1(tag(", "), usize)(input)?;
-    let (input, _) = tag("\n  Operation: new = old ")(input)?;
-    let (input, operator) = operator(input)?;
-    let (input, _) = tag(" ")(input)?;
-    let (input, operand) = value(input)?;
-    let (input, _) = tag("\n  Test: divisible by ")(input)?;
-    let (input, divisible_by) = usize(input)?;
-    let (input, _) = tag("\n    If true: throw to monkey ")(input)?;
-    let (input, if_true) = usize(input)?;
-    let (input, _) = tag("\n    If false: throw to monkey ")(input)?;
-    let (input, if_false) = usize(input)?;
-    let (input, _) = tag("\n")(input)?;
-    Ok((
-        input,
-        Monkey {
-            number,
-            starting_items,
-            operator,
-            operand,
-            divisible_by,
-            if_true,
-            if_false,
-        },
-    ))
-}
-
-fn usize(input: &str) -> IResult<&str, usize> {
-    map_res(digit1, |s: &str| s.parse::<usize>())(input)
+pub fn monkey(input: &str) -> IResult<'_, Monkey> {
+    context("monkey", |input| {
+        let (input, _) = tag("Monkey ")(input)?;
+        let (input, number) = unsigned(input)?;
+        let (input, _) = tag(":\n  Starting items: ")(input)?;
+        let (input, starting_items) = separated_list1(tag(", "), unsigned)(input)?;
+        let (input, _) = tag("\n  Operation: new = old ")(input)?;
+        let (input, operator) = operator(input)?;
+        let (input, _) = tag(" ")(input)?;
+        let (input, operand) = value(input)?;
+        let (input, _) = tag("\n  Test: divisible by ")(input)?;
+        let (input, divisible_by) = unsigned(input)?;
+        let (input, _) = tag("\n    If true: throw to monkey ")(input)?;
+        let (input, if_true) = unsigned(input)?;
+        let (input, _) = tag("\n    If false: throw to monkey ")(input)?;
+        let (input, if_false) = unsigned(input)?;
+        Ok((
+            input,
+            Monkey {
+                number,
+                starting_items,
+                operator,
+                operand,
+                divisible_by,
+                if_true,
+                if_false,
+            },
+        ))
+    })(input)
 }
 
-fn operator(input: &str) -> IResult<&str, Operator> {
+fn operator(input: &str) -> IResult<'_, Operator> {
     let (input, op_str) = alt((tag("+"), tag("*")))(input)?;
     Ok((
         input,
@@ -82,34 +81,37 @@ fn operator(input: &str) -> IResult<&str, Operator> {
     ))
 }
 
-fn value(input: &str) -> IResult<&str, Value> {
+fn value(input: &str) -> IResult<'_, Value> {
     if let (input, Some(_)) = opt(tag("old"))(input)? {
         Ok((input, Value::Old))
     } else {
-        let (input, number) = usize(input)?;
+        let (input, number) = unsigned(input)?;
         Ok((input, Value::Num(number)))
     }
 }
 
 ///////////////////////////////////////////
 
-pub fn main() {
-    let (_, input) =
-        separated_list1(tag("\n"), monkey)(include_str!("../inputs/day11.txt")).unwrap();
+pub const TITLE: &str = "Day 11";
+pub const INPUT: &str = include_str!("../inputs/day11.txt");
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let input = parse::finish(input, blocks(monkey)).unwrap_or_else(|err| panic!("{err}"));
 
     for (index, monke) in input.iter().enumerate() {
         assert_eq!(index, monke.number);
     }
 
-    println!("--- Day 11 ---");
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    span.exit();
+    [part1(&input).into(), part2(&input).into()]
 }
 
 fn run(input: &[Monkey], rounds: usize, limiter: impl Fn(&mut usize)) -> usize {
     let mut inspect_counts = vec![0; input.len()];
     let mut monkey_items = input.iter().map(|m| m.starting_items.clone()).collect_vec();
-    for _ in 0..rounds {
+    for round in 1..=rounds {
+        cancel::checkpoint();
         for monke in input {
             for mut worry_level in mem::take(&mut monkey_items[monke.number]) {
                 inspect_counts[monke.number] += 1;
@@ -126,16 +128,19 @@ fn run(input: &[Monkey], rounds: usize, limiter: impl Fn(&mut usize)) -> usize {
                 }
             }
         }
+        debug!(round, ?inspect_counts, "round finished");
     }
     // sort in reverse order
     inspect_counts.sort_unstable_by(|a, b| b.cmp(a));
     inspect_counts[0] * inspect_counts[1]
 }
 
+#[instrument(skip_all)]
 fn part1(input: &[Monkey]) -> usize {
     run(input, 20, |worry_level| *worry_level /= 3)
 }
 
+#[instrument(skip_all)]
 fn part2(input: &[Monkey]) -> usize {
     // lowest common multiple of all `divisible_by`s
     let lcm = input
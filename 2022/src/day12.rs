@@ -0,0 +1,194 @@
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+    thread,
+};
+
+use tracing::{info_span, instrument};
+
+use aoc_utils::{
+    answer::Answer,
+    cancel, config, frames,
+    grid::Grid,
+    point::Point,
+    search::{self, BestFirst, SearchTree},
+    term::{self, Style},
+};
+
+const TEST: bool = false;
+const DEBUG: bool = false;
+
+pub const TITLE: &str = "Day 12";
+pub const INPUT: &str = match TEST {
+    true => TEST_INPUT,
+    false => include_str!("../inputs/day12.txt"),
+};
+pub const TEST_INPUT: &str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let (start_pos, end_pos, heightmap) = parse_heightmap(input);
+    span.exit();
+    [
+        part1(&heightmap, start_pos, end_pos).into(),
+        part2(&heightmap, end_pos).into(),
+    ]
+}
+
+pub fn parse_heightmap(input: &str) -> (Point, Point, Grid<u8>) {
+    let mut start_pos = Point::ORIGIN;
+    let mut end_pos = Point::ORIGIN;
+    let heightmap = Grid::parse(input, |pos, char| match char {
+        'S' => {
+            start_pos = pos;
+            0
+        }
+        'E' => {
+            end_pos = pos;
+            b'z' - b'a'
+        }
+        _ => char as u8 - b'a',
+    });
+    (start_pos, end_pos, heightmap)
+}
+
+/// Returns the positions reachable from `pos` by climbing at most one level, each one step away.
+fn climbable(heightmap: &Grid<u8>, pos: Point) -> impl Iterator<Item = (Point, u64)> + '_ {
+    cancel::checkpoint();
+    heightmap
+        .neighbors(pos)
+        .filter(move |neighbor| heightmap[*neighbor] <= heightmap[pos] + 1)
+        .map(|neighbor| (neighbor, 1))
+}
+
+/// Runs `search` until it reaches `end_pos` and returns the number of steps it took.
+fn steps_to<F, H>(mut search: BestFirst<Point, F, H>, heightmap: &Grid<u8>, end_pos: Point) -> u64
+where
+    BestFirst<Point, F, H>: Iterator<Item = (Point, u64)>,
+{
+    if DEBUG {
+        term::start_animation();
+    }
+    let steps = loop {
+        let (pos, steps) = search.next().expect("the end is reachable");
+        if DEBUG || frames::watching() {
+            show_map(pos, heightmap, search.tree(), DEBUG);
+        }
+        if pos == end_pos {
+            break steps;
+        }
+    };
+    if DEBUG {
+        term::end_animation();
+    }
+    steps
+}
+
+//////////////////////////////////
+
+#[instrument(skip_all)]
+fn part1(heightmap: &Grid<u8>, start_pos: Point, end_pos: Point) -> u64 {
+    let search = search::astar(
+        [start_pos],
+        |pos| climbable(heightmap, pos),
+        |pos| pos.manhattan(end_pos),
+    );
+    steps_to(search, heightmap, end_pos)
+}
+
+#[instrument(skip_all)]
+fn part2(heightmap: &Grid<u8>, end_pos: Point) -> u64 {
+    let lowest = heightmap
+        .iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|(pos, _)| pos);
+    let search = search::dijkstra(lowest, |pos| climbable(heightmap, pos));
+    steps_to(search, heightmap, end_pos)
+}
+
+/// The colors of the glyphs of [`render_map`] on the dashboard.
+const LEGEND: &[(char, &str)] = &[
+    ('@', "#2e7d32"),
+    ('O', "#26c6da"),
+    ('X', "#9e9e9e"),
+    ('.', "#263238"),
+];
+
+/// Shows the positions reached by a search, highlighting `pos` and the path leading to it, on the
+/// terminal if `debug` and on the dashboard if it is watching.
+pub fn show_map(pos: Point, heightmap: &Grid<u8>, tree: &SearchTree<Point>, debug: bool) {
+    let mut map = Grid::new(heightmap.width(), heightmap.height(), None);
+    for (reached, steps) in tree.distances() {
+        map[reached] = Some(steps as usize);
+    }
+    let path = tree.path_to(pos).unwrap_or_default().into_iter().collect();
+
+    if debug {
+        let mut stdout = io::stdout().lock();
+        term::next_frame(&mut stdout).unwrap();
+        render_map(&mut stdout, pos, &map, &path, term::color()).unwrap();
+    }
+    frames::send(LEGEND, |out| render_map(out, pos, &map, &path, false));
+    thread::sleep(config::get().frame_delay());
+}
+
+fn render_map(
+    out: &mut impl Write,
+    pos: Point,
+    map: &Grid<Option<usize>>,
+    path: &HashSet<Point>,
+    color: bool,
+) -> io::Result<()> {
+    for (y, row) in map.rows().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            let cell_pos = Point::new(x as i64, y as i64);
+            let glyph = match (cell_pos == pos, path.contains(&cell_pos)) {
+                (true, _) => term::glyph(color, Style::BG_GREEN, "O", "@"),
+                (false, true) => term::glyph(color, Style::BG_CYAN, "O", "O"),
+                (false, false) => match cell {
+                    Some(_) => "X".to_owned(),
+                    None => ".".to_owned(),
+                },
+            };
+            write!(out, "{glyph}")?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_utils::assert_renders;
+
+    #[test]
+    fn map() {
+        let (start_pos, end_pos, heightmap) = parse_heightmap(TEST_INPUT);
+        let mut step_counts = Grid::new(heightmap.width(), heightmap.height(), None);
+        step_counts[end_pos] = Some(0);
+        let path = [(5, 2), (4, 2), (4, 1), (3, 1)].map(|(x, y)| Point::new(x, y));
+        for pos in path {
+            step_counts[pos] = Some(0);
+        }
+        step_counts[start_pos] = Some(0);
+
+        assert_renders("day12_map", |color| {
+            let mut out = vec![];
+            render_map(
+                &mut out,
+                Point::new(3, 0),
+                &step_counts,
+                &path.into_iter().collect(),
+                color,
+            )
+            .unwrap();
+            String::from_utf8(out).unwrap()
+        });
+    }
+}
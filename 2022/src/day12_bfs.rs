@@ -0,0 +1,68 @@
+use tracing::{info_span, instrument};
+
+use aoc_utils::{
+    answer::Answer,
+    frames,
+    grid::Grid,
+    point::Point,
+    search::{self, SearchTree},
+    term,
+};
+
+use crate::day12::{parse_heightmap, show_map, TEST_INPUT};
+
+const TEST: bool = false;
+const DEBUG: bool = false;
+
+pub const TITLE: &str = "Day 12 (BFS)";
+pub const INPUT: &str = match TEST {
+    true => TEST_INPUT,
+    false => include_str!("../inputs/day12.txt"),
+};
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let (start_pos, end_pos, heightmap) = parse_heightmap(input);
+    span.exit();
+
+    // search backwards from the end, so one search answers both parts
+    let span = info_span!("search").entered();
+    let map = &heightmap;
+    let mut search = search::bfs([end_pos], |pos| {
+        map.neighbors(pos)
+            .filter(move |neighbor| map[pos] <= map[*neighbor] + 1)
+    });
+    if DEBUG {
+        term::start_animation();
+    }
+    while let Some((pos, _)) = search.next() {
+        if DEBUG || frames::watching() {
+            show_map(pos, &heightmap, search.tree(), DEBUG);
+        }
+    }
+    if DEBUG {
+        term::end_animation();
+    }
+    let step_counts = search.into_tree();
+    span.exit();
+
+    [
+        part1(&step_counts, start_pos).into(),
+        part2(&step_counts, &heightmap).into(),
+    ]
+}
+
+#[instrument(skip_all)]
+fn part1(step_counts: &SearchTree<Point>, start_pos: Point) -> u64 {
+    step_counts.distance(start_pos).unwrap()
+}
+
+#[instrument(skip_all)]
+fn part2(step_counts: &SearchTree<Point>, heightmap: &Grid<u8>) -> u64 {
+    step_counts
+        .distances()
+        .filter(|(pos, _)| heightmap[*pos] == 0)
+        .map(|(_, steps)| steps)
+        .min()
+        .unwrap()
+}
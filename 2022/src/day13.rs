@@ -0,0 +1,157 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+
+use itertools::Itertools;
+use nom::{
+    bytes::complete::tag, character::complete::line_ending, multi::separated_list0,
+    sequence::separated_pair,
+};
+use tracing::{debug, info_span, instrument, trace};
+
+use aoc_utils::{
+    answer::Answer,
+    parse::{self, blocks, unsigned, IResult},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Int(u8),
+    List(Vec<Value>),
+}
+
+////////////////////////////////
+
+fn pair(input: &str) -> IResult<'_, [Value; 2]> {
+    let (input, (left, right)) = separated_pair(value, line_ending, value)(input)?;
+    Ok((input, [left, right]))
+}
+
+pub fn value(input: &str) -> IResult<'_, Value> {
+    if input.starts_with('[') {
+        let (input, list) = list(input)?;
+        Ok((input, Value::List(list)))
+    } else {
+        let (input, num) = unsigned(input)?;
+        Ok((input, Value::Int(num)))
+    }
+}
+
+fn list(input: &str) -> IResult<'_, Vec<Value>> {
+    let (input, _) = tag("[")(input)?;
+    let (input, list) = separated_list0(tag(","), value)(input)?;
+    let (input, _) = tag("]")(input)?;
+    Ok((input, list))
+}
+
+////////////////////////////////
+
+pub const TITLE: &str = "Day 13";
+pub const INPUT: &str = include_str!("../inputs/day13.txt");
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let input = parse::finish(input, blocks(pair)).unwrap_or_else(|err| panic!("{err}"));
+    span.exit();
+    [part1(&input).into(), part2(&input).into()]
+}
+
+////////////////////////////////
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(num) => write!(f, "{num}"),
+            Value::List(list) => write!(f, "[{}]", list.iter().join(",")),
+        }
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Value::Int(left), Value::Int(right)) => {
+                let ord = left.cmp(right);
+                if ord != Ordering::Equal {
+                    trace!(left, right, ?ord, "integers decide");
+                }
+                ord
+            }
+            (Value::List(left), Value::List(right)) => {
+                let left_iter = left.iter();
+                let mut right_iter = right.iter();
+                for left in left_iter {
+                    match right_iter.next() {
+                        Some(right) => {
+                            let ord = left.cmp(right);
+                            if ord != Ordering::Equal {
+                                return ord;
+                            }
+                        }
+                        None => {
+                            trace!("right list ran out first");
+                            return Ordering::Greater;
+                        }
+                    }
+                }
+                if right_iter.next().is_some() {
+                    trace!("left list ran out first");
+                    return Ordering::Less;
+                }
+                Ordering::Equal
+            }
+            (left @ Value::Int(_), right @ Value::List(_)) => {
+                Value::List(vec![left.clone()]).cmp(right)
+            }
+            (left @ Value::List(_), right @ Value::Int(_)) => {
+                left.cmp(&Value::List(vec![right.clone()]))
+            }
+        }
+    }
+}
+
+////////////////////////////////
+
+#[instrument(skip_all)]
+fn part1(input: &[[Value; 2]]) -> usize {
+    input
+        .iter()
+        .enumerate()
+        .map(|(index, [left, right])| {
+            let ordered = left < right;
+            debug!(pair = index + 1, %left, %right, ordered, "compared pair");
+            if ordered {
+                index + 1
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+#[instrument(skip_all)]
+fn part2(input: &[[Value; 2]]) -> usize {
+    let mut packets = input.iter().flatten().collect_vec();
+    let divider_packet_1 = Value::List(vec![Value::List(vec![Value::Int(2)])]);
+    let divider_packet_2 = Value::List(vec![Value::List(vec![Value::Int(6)])]);
+    packets.push(&divider_packet_1);
+    packets.push(&divider_packet_2);
+    packets.sort_unstable();
+    (packets
+        .iter()
+        .position(|val| val == &&divider_packet_1)
+        .unwrap()
+        + 1)
+        * (packets
+            .iter()
+            .position(|val| val == &&divider_packet_2)
+            .unwrap()
+            + 1)
+}
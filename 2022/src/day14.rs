@@ -0,0 +1,234 @@
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+    thread,
+};
+
+use itertools::Itertools;
+use nom::{bytes::complete::tag, multi::separated_list1};
+use tracing::{info_span, instrument};
+
+use aoc_utils::{
+    answer::Answer,
+    cancel, config, frames,
+    grid::Grid,
+    parse::{self, lines, point},
+    point::{Direction8, Point},
+    term::{self, Style},
+};
+
+const TEST: bool = false;
+const DEBUG_PART_1: bool = false;
+const DEBUG_PART_2: bool = false;
+pub const SOURCE: Point = Point::new(500, 0);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Tile {
+    fn glyph(self, color: bool) -> String {
+        match self {
+            Tile::Air => ".".to_owned(),
+            Tile::Rock => term::glyph(color, Style::BG_WHITE, " ", "#"),
+            Tile::Sand => term::glyph(color, Style::BG_YELLOW, " ", "o"),
+        }
+    }
+}
+
+pub const TITLE: &str = "Day 14";
+pub const INPUT: &str = match TEST {
+    true => {
+        "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9"
+    }
+    false => include_str!("../inputs/day14.txt"),
+};
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let map = parse(input);
+    span.exit();
+
+    if DEBUG_PART_1 || DEBUG_PART_2 {
+        term::start_animation();
+    }
+    let answers = [part1(&map).into(), part2(&map).into()];
+    if DEBUG_PART_1 || DEBUG_PART_2 {
+        term::end_animation();
+    }
+    answers
+}
+
+pub fn parse(input: &str) -> Grid<Tile> {
+    let splines = parse::finish(input, lines(separated_list1(tag(" -> "), point)))
+        .unwrap_or_else(|err| panic!("{err}"));
+    // width = max x idx + 1
+    let width = splines.iter().flatten().map(|knot| knot.x).max().unwrap() + 1;
+    // height = max y idx + 1 + 2 for floor in part two
+    let height = splines.iter().flatten().map(|knot| knot.y).max().unwrap() + 3;
+    // create empty map
+    let mut map = Grid::new(width as usize, height as usize, Tile::Air);
+    // fill map with rocks
+    for spline in splines {
+        for (knot1, knot2) in spline.into_iter().tuple_windows() {
+            for y in knot1.y.min(knot2.y)..=knot1.y.max(knot2.y) {
+                for x in knot1.x.min(knot2.x)..=knot1.x.max(knot2.x) {
+                    map[Point::new(x, y)] = Tile::Rock;
+                }
+            }
+        }
+    }
+    map
+}
+
+pub fn try_move(map: &Grid<Tile>, pos: &mut Point) -> bool {
+    for direction in [
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::DownRight,
+    ] {
+        if map
+            .get(*pos + direction)
+            .is_none_or(|tile| tile == &Tile::Air)
+        {
+            *pos = *pos + direction;
+            return true;
+        }
+    }
+    false
+}
+
+#[instrument(skip_all)]
+fn part1(map: &Grid<Tile>) -> usize {
+    let mut map = map.clone();
+    let mut count = 0;
+    'outer: loop {
+        cancel::checkpoint();
+        let mut pos = SOURCE;
+        loop {
+            if !map.contains(pos) {
+                break 'outer;
+            } else if !try_move(&map, &mut pos) {
+                break;
+            }
+        }
+        map[pos] = Tile::Sand;
+        count += 1;
+        if DEBUG_PART_1 || frames::watching() {
+            show_map(&map, &HashSet::new(), DEBUG_PART_1);
+        }
+    }
+    if DEBUG_PART_2 {
+        show_map(&map, &HashSet::new(), true);
+    }
+    count
+}
+
+#[instrument(skip_all)]
+fn part2(map: &Grid<Tile>) -> usize {
+    let mut map = map.clone();
+    let floor = map.height() as i64 - 1;
+    for x in 0..map.width() as i64 {
+        map[Point::new(x, floor)] = Tile::Rock;
+    }
+    let mut count = 0;
+    let mut reached_end = false;
+    while !reached_end {
+        cancel::checkpoint();
+        let mut pos = SOURCE;
+        loop {
+            // extend map to the right if necessary
+            if pos.x + 1 == map.width() as i64 {
+                map.grow([0, 0, 1, 0], Tile::Air);
+                map[Point::new(pos.x + 1, floor)] = Tile::Rock;
+            }
+
+            // move sand unit
+            if !try_move(&map, &mut pos) {
+                reached_end = pos == SOURCE;
+                break;
+            }
+        }
+        if DEBUG_PART_2 || frames::watching() {
+            show_map(&map, &HashSet::new(), DEBUG_PART_2);
+        }
+        map[pos] = Tile::Sand;
+        count += 1;
+    }
+    if DEBUG_PART_2 {
+        show_map(&map, &HashSet::new(), true);
+    }
+    count
+}
+
+/// The colors of the glyphs of [`render_map`] on the dashboard.
+const LEGEND: &[(char, &str)] = &[
+    ('#', "#eceff1"),
+    ('o', "#fbc02d"),
+    ('~', "#e53935"),
+    ('.', "#263238"),
+];
+
+/// Shows the map, highlighting the `falling` units of sand, on the terminal if `debug` and on the
+/// dashboard if it is watching.
+pub fn show_map(map: &Grid<Tile>, falling: &HashSet<Point>, debug: bool) {
+    if debug {
+        let mut stdout = io::stdout().lock();
+        term::next_frame(&mut stdout).unwrap();
+        render_map(&mut stdout, map, falling, term::color()).unwrap();
+    }
+    frames::send(LEGEND, |out| render_map(out, map, falling, false));
+    thread::sleep(config::get().frame_delay());
+}
+
+fn render_map(
+    out: &mut impl Write,
+    map: &Grid<Tile>,
+    falling: &HashSet<Point>,
+    color: bool,
+) -> io::Result<()> {
+    // get left-most non-air tile
+    let x_min = map
+        .rows()
+        .rev()
+        .skip(1) // do not consider infinite floor for x bounds
+        .map(|row| row.iter().take_while(|tile| tile == &&Tile::Air).count())
+        .min()
+        .unwrap();
+    for (y, row) in map.rows().enumerate() {
+        for (x, tile) in row.iter().enumerate().skip(x_min) {
+            match falling.contains(&Point::new(x as i64, y as i64)) {
+                true => write!(out, "{}", term::glyph(color, Style::BG_RED, " ", "~"))?,
+                false => write!(out, "{}", tile.glyph(color))?,
+            }
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_renders;
+
+    const INPUT: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn map() {
+        let mut map = parse(INPUT);
+        map[Point::new(500, 8)] = Tile::Sand;
+        map[Point::new(499, 8)] = Tile::Sand;
+        let falling = HashSet::from([Point::new(500, 6)]);
+        assert_renders("day14_map", |color| {
+            let mut out = vec![];
+            render_map(&mut out, &map, &falling, color).unwrap();
+            String::from_utf8(out).unwrap()
+        });
+    }
+}
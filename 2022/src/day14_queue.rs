@@ -0,0 +1,105 @@
+use std::collections::{HashSet, VecDeque};
+
+use tracing::{info_span, instrument};
+
+use aoc_utils::{answer::Answer, cancel, frames, grid::Grid, point::Point, term};
+
+use crate::day14::{parse, show_map, try_move, Tile, SOURCE};
+
+const TEST: bool = false;
+const DEBUG: bool = false;
+
+pub const TITLE: &str = "Day 14 (queue)";
+pub const INPUT: &str = match TEST {
+    true => {
+        "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9"
+    }
+    false => include_str!("../inputs/day14.txt"),
+};
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let map = parse(input);
+    span.exit();
+
+    if DEBUG {
+        term::start_animation();
+    }
+    let answers = [part1(&map).into(), part2(&map).into()];
+    if DEBUG {
+        term::end_animation();
+    }
+    answers
+}
+
+#[instrument(skip_all)]
+fn part1(map: &Grid<Tile>) -> usize {
+    simulate(map.clone(), None)
+}
+
+#[instrument(skip_all)]
+fn part2(map: &Grid<Tile>) -> usize {
+    let mut map = map.clone();
+    let floor = map.height() as i64 - 1;
+    for x in 0..map.width() as i64 {
+        map[Point::new(x, floor)] = Tile::Rock;
+    }
+    simulate(map, Some(floor))
+}
+
+/// Releases one unit of sand per step while moving all falling units at once, and returns how
+/// many came to rest before one fell into the abyss or the source was blocked.
+///
+/// With a `floor`, the map is extended to the right whenever a unit reaches its edge.
+fn simulate(mut map: Grid<Tile>, floor: Option<i64>) -> usize {
+    // the units ahead came first, so they are the ones that come to rest first
+    let mut falling = VecDeque::new();
+    let mut count = 0;
+    loop {
+        cancel::checkpoint();
+        falling.push_back(SOURCE);
+        let mut done = false;
+        falling.retain_mut(|pos| {
+            if done {
+                return true;
+            }
+            if let Some(floor) = floor {
+                if pos.x + 1 == map.width() as i64 {
+                    map.grow([0, 0, 1, 0], Tile::Air);
+                    map[Point::new(pos.x + 1, floor)] = Tile::Rock;
+                }
+            }
+            if try_move(&map, pos) {
+                done = !map.contains(*pos);
+                return true;
+            }
+            map[*pos] = Tile::Sand;
+            count += 1;
+            done = *pos == SOURCE;
+            false
+        });
+        if DEBUG || frames::watching() {
+            let falling = falling.iter().copied().collect::<HashSet<_>>();
+            show_map(&map, &falling, DEBUG);
+        }
+        if done {
+            return count;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_one_unit_at_a_time() {
+        let map = parse(
+            "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9",
+        );
+        assert_eq!(part1(&map), 24);
+        assert_eq!(part2(&map), 93);
+    }
+}
@@ -1,17 +1,24 @@
-pub fn main() {
-    let input: Vec<[i64; 2]> = include_str!("../inputs/day2.txt")
+use tracing::{info_span, instrument};
+
+use aoc_utils::answer::Answer;
+
+pub const TITLE: &str = "Day 2";
+pub const INPUT: &str = include_str!("../inputs/day2.txt");
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let input: Vec<[i64; 2]> = input
         .lines()
         .map(|l| {
             let (elve, me) = l.split_once(' ').unwrap();
             [elve.as_bytes()[0] as i64 - 64, me.as_bytes()[0] as i64 - 87]
         })
         .collect();
-
-    println!("--- Day 2 ---");
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+    span.exit();
+    [part1(&input).into(), part2(&input).into()]
 }
 
+#[instrument(skip_all)]
 fn part1(input: &[[i64; 2]]) -> i64 {
     // win situations:
     // 3 1
@@ -35,6 +42,7 @@ fn part1(input: &[[i64; 2]]) -> i64 {
         .sum()
 }
 
+#[instrument(skip_all)]
 fn part2(input: &[[i64; 2]]) -> i64 {
     // win shape: (elve % 3) + 1
     // lose shape: ((elve + 1) % 3) + 1
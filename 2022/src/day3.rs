@@ -1,12 +1,18 @@
 use std::collections::HashSet;
 
 use itertools::Itertools;
+use tracing::{info_span, instrument};
 
-pub fn main() {
-    let input: Vec<_> = include_str!("../inputs/day3.txt").lines().collect();
-    println!("--- Day 3 ---");
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+use aoc_utils::answer::Answer;
+
+pub const TITLE: &str = "Day 3";
+pub const INPUT: &str = include_str!("../inputs/day3.txt");
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let input: Vec<_> = input.lines().collect();
+    span.exit();
+    [part1(&input).into(), part2(&input).into()]
 }
 
 fn char_score(char: char) -> u64 {
@@ -16,6 +22,7 @@ fn char_score(char: char) -> u64 {
     }
 }
 
+#[instrument(skip_all)]
 fn part1(input: &[&str]) -> u64 {
     input
         .iter()
@@ -32,6 +39,7 @@ fn part1(input: &[&str]) -> u64 {
         .sum()
 }
 
+#[instrument(skip_all)]
 fn part2(input: &[&str]) -> u64 {
     input
         .iter()
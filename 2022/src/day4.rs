@@ -0,0 +1,40 @@
+use itertools::Itertools;
+use tracing::{info_span, instrument};
+
+use aoc_utils::{answer::Answer, range_set::RangeSet};
+
+pub const TITLE: &str = "Day 4";
+pub const INPUT: &str = include_str!("../inputs/day4.txt");
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let input: Vec<RangeSet> = input
+        .lines()
+        .flat_map(|line| line.split(',').flat_map(|split| split.split('-')))
+        .tuples()
+        .map(|(start, end)| RangeSet::from(start.parse().unwrap()..=end.parse().unwrap()))
+        .collect();
+    span.exit();
+    [part1(&input).into(), part2(&input).into()]
+}
+
+#[instrument(skip_all)]
+fn part1(input: &[RangeSet]) -> usize {
+    input
+        .iter()
+        .tuples()
+        .filter(|(left, right)| {
+            let union = left.union(right);
+            union == **left || union == **right
+        })
+        .count()
+}
+
+#[instrument(skip_all)]
+fn part2(input: &[RangeSet]) -> usize {
+    input
+        .iter()
+        .tuples()
+        .filter(|(left, right)| !left.intersection(right).is_empty())
+        .count()
+}
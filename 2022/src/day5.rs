@@ -1,7 +1,13 @@
-pub fn main() {
-    let (stack_input, instruction_input) = include_str!("../inputs/day5.txt")
-        .split_once("\n\n")
-        .unwrap();
+use tracing::{debug, info_span, instrument};
+
+use aoc_utils::answer::Answer;
+
+pub const TITLE: &str = "Day 5";
+pub const INPUT: &str = include_str!("../inputs/day5.txt");
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let (stack_input, instruction_input) = input.split_once("\n\n").unwrap();
     let indices: Vec<usize> = (1..).step_by(4).take(9).collect();
     let stack_input: Vec<Vec<&str>> = indices
         .iter()
@@ -26,14 +32,18 @@ pub fn main() {
             )
         })
         .collect();
-    println!("--- Day 5 ---");
-    println!("Part 1: {}", part1(&stack_input, &instruction_input));
-    println!("Part 2: {}", part2(&stack_input, &instruction_input));
+    span.exit();
+    [
+        part1(&stack_input, &instruction_input).into(),
+        part2(&stack_input, &instruction_input).into(),
+    ]
 }
 
+#[instrument(skip_all)]
 fn part1(stack_input: &[Vec<&str>], instructions: &[(usize, usize, usize)]) -> String {
     let mut stacks = stack_input.to_vec();
     for (count, from, to) in instructions {
+        debug!(count, from, to, "crane moves crates one by one");
         for _ in 0..*count {
             let crate_ = stacks[*from - 1].pop().unwrap();
             stacks[*to - 1].push(crate_);
@@ -45,9 +55,11 @@ fn part1(stack_input: &[Vec<&str>], instructions: &[(usize, usize, usize)]) -> S
         .collect()
 }
 
+#[instrument(skip_all)]
 fn part2(stack_input: &[Vec<&str>], instructions: &[(usize, usize, usize)]) -> String {
     let mut stacks = stack_input.to_vec();
     for (count, from, to) in instructions {
+        debug!(count, from, to, "crane moves crates at once");
         let mut buffer = vec![];
         for _ in 0..*count {
             let crate_ = stacks[*from - 1].pop().unwrap();
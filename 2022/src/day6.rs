@@ -0,0 +1,27 @@
+use itertools::Itertools;
+use tracing::info_span;
+
+use aoc_utils::answer::Answer;
+
+pub const TITLE: &str = "Day 6";
+pub const INPUT: &str = include_str!("../inputs/day6.txt");
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let input = input.as_bytes();
+    [
+        info_span!("part1")
+            .in_scope(|| find_unique_window(input, 4))
+            .into(),
+        info_span!("part2")
+            .in_scope(|| find_unique_window(input, 14))
+            .into(),
+    ]
+}
+
+fn find_unique_window(input: &[u8], size: usize) -> usize {
+    input
+        .windows(size)
+        .take_while(|window| !window.iter().all_unique())
+        .count()
+        + size
+}
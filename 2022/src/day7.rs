@@ -3,6 +3,11 @@ use std::{
     rc::{Rc, Weak},
 };
 
+use serde::Deserialize;
+use tracing::{info_span, instrument};
+
+use aoc_utils::{answer::Answer, config};
+
 #[derive(Debug)]
 struct File {
     kind: FileKind,
@@ -25,9 +30,31 @@ impl FileKind {
     }
 }
 
+/// The parameters of part 2, shared with `day7_alternative`.
+#[derive(Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub disk_size: usize,
+    /// The free space needed for the update
+    pub required_space: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            disk_size: 70_000_000,
+            required_space: 30_000_000,
+        }
+    }
+}
+
 //////////////////////////////////////////////////////
 
-pub fn main() {
+pub const TITLE: &str = "Day 7";
+pub const INPUT: &str = include_str!("../inputs/day7.txt");
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
     let file_system = Rc::new(File {
         kind: FileKind::Directory {
             children: vec![].into(),
@@ -36,7 +63,7 @@ pub fn main() {
         parent: None,
     });
     let mut current_file = Rc::clone(&file_system);
-    for command in include_str!("../inputs/day7.txt").split("$ ").skip(1) {
+    for command in input.split("$ ").skip(1) {
         let (command, output) = command.split_once('\n').unwrap();
         match command.split_once(' ') {
             Some(("cd", "/")) => {
@@ -60,7 +87,7 @@ pub fn main() {
                             kind: FileKind::Directory {
                                 children: vec![].into(),
                             },
-                            name: dir.to_string(),
+                            name: dir.into(),
                             parent: Some(Rc::downgrade(&current_file)),
                         });
                         children.push(Rc::clone(&new_dir));
@@ -94,7 +121,7 @@ pub fn main() {
                     current_file.kind.unwrap_dir().borrow_mut().push(
                         File {
                             kind,
-                            name: name.to_string(),
+                            name: name.into(),
                             parent: Some(Rc::downgrade(&current_file)),
                         }
                         .into(),
@@ -103,24 +130,28 @@ pub fn main() {
             }
         }
     }
-    println!("--- Day 7 ---");
-    println!("Part 1: {}", part1(&file_system));
-    println!("Part 2: {}", part2(&file_system));
+    span.exit();
+    [
+        part1(&file_system).into(),
+        part2(&file_system, &config::get().params("day7")).into(),
+    ]
 }
 
 //////////////////////////////////////////////////////
 
+#[instrument(skip_all)]
 fn part1(file: &Rc<File>) -> usize {
     let mut dirs = vec![];
     calculate_size(file, &mut dirs);
     dirs.into_iter().filter(|dir| *dir <= 100_000).sum()
 }
 
-fn part2(file: &Rc<File>) -> usize {
+#[instrument(skip_all)]
+fn part2(file: &Rc<File>, params: &Params) -> usize {
     let mut dirs = vec![];
     let root_size = calculate_size(file, &mut dirs);
-    let free_space = 70_000_000 - root_size;
-    let missing_space = 30_000_000 - free_space;
+    let free_space = params.disk_size - root_size;
+    let missing_space = params.required_space - free_space;
     dirs.sort_unstable();
     dirs.into_iter()
         .find(|size| *size >= missing_space)
@@ -3,6 +3,12 @@ use std::{
     path::{Path, PathBuf},
 };
 
+use tracing::{info_span, instrument};
+
+use aoc_utils::{answer::Answer, config};
+
+use crate::day7::Params;
+
 #[derive(Debug)]
 enum Entry {
     Dir(Vec<PathBuf>),
@@ -18,11 +24,15 @@ impl Entry {
     }
 }
 
-pub fn main() {
+pub const TITLE: &str = "Day 7 (alternative)";
+pub const INPUT: &str = include_str!("../inputs/day7.txt");
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
     let mut cwd = PathBuf::from("/");
     let mut file_system: HashMap<PathBuf, Entry> =
         HashMap::from([(cwd.clone(), Entry::Dir(vec![]))]);
-    for line in include_str!("../inputs/day7.txt").lines() {
+    for line in input.lines() {
         match &line[..4] {
             "$ cd" => match &line[5..] {
                 "/" => cwd = PathBuf::from("/"),
@@ -53,9 +63,11 @@ pub fn main() {
     let mut sizes: Vec<usize> = vec![];
     calculate_sizes(&mut sizes, &file_system, Path::new("/"));
 
-    println!("--- Day 7 (alternative) ---");
-    println!("Part 1: {}", part1(&sizes));
-    println!("Part 2: {}", part2(&mut sizes));
+    span.exit();
+    [
+        part1(&sizes).into(),
+        part2(&mut sizes, &config::get().params("day7")).into(),
+    ]
 }
 
 fn calculate_sizes(
@@ -76,14 +88,16 @@ fn calculate_sizes(
     }
 }
 
+#[instrument(skip_all)]
 fn part1(sizes: &[usize]) -> usize {
     sizes.iter().filter(|dir| **dir <= 100_000).sum()
 }
 
-fn part2(sizes: &mut [usize]) -> usize {
+#[instrument(skip_all)]
+fn part2(sizes: &mut [usize], params: &Params) -> usize {
     let root_size = sizes.last().unwrap();
-    let free_space = 70_000_000 - root_size;
-    let missing_space = 30_000_000 - free_space;
+    let free_space = params.disk_size - root_size;
+    let missing_space = params.required_space - free_space;
     sizes.sort_unstable();
     *sizes.iter().find(|size| **size >= missing_space).unwrap()
 }
@@ -0,0 +1,60 @@
+use take_until::TakeUntilExt;
+use tracing::{info_span, instrument};
+
+use aoc_utils::{answer::Answer, grid::Grid};
+
+pub const TITLE: &str = "Day 8";
+pub const INPUT: &str = include_str!("../inputs/day8.txt");
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let input = Grid::parse(input, |_, tree| tree as u8 - b'0');
+    span.exit();
+    [part1(&input).into(), part2(&input).into()]
+}
+
+#[instrument(skip_all)]
+fn part1(input: &Grid<u8>) -> usize {
+    input
+        .iter()
+        .filter(|&(pos, &tree)| {
+            let (x, y) = (pos.x as usize, pos.y as usize);
+            let (row, column) = (input.row(y), input.column(x));
+            row[..x].iter().all(|other| *other < tree)
+                || row[x + 1..].iter().all(|other| *other < tree)
+                || column.clone().take(y).all(|other| *other < tree)
+                || column.skip(y + 1).all(|other| *other < tree)
+        })
+        .count()
+}
+
+#[instrument(skip_all)]
+fn part2(input: &Grid<u8>) -> usize {
+    input
+        .iter()
+        .map(|(pos, &tree)| {
+            let (x, y) = (pos.x as usize, pos.y as usize);
+            let (row, column) = (input.row(y), input.column(x));
+            row[..x]
+                .iter()
+                .rev()
+                .take_until(|other| **other >= tree)
+                .count()
+                * row[x + 1..]
+                    .iter()
+                    .take_until(|other| **other >= tree)
+                    .count()
+                * column
+                    .clone()
+                    .take(y)
+                    .rev()
+                    .take_until(|other| **other >= tree)
+                    .count()
+                * column
+                    .skip(y + 1)
+                    .take_until(|other| **other >= tree)
+                    .count()
+        })
+        .max()
+        .unwrap()
+}
@@ -0,0 +1,320 @@
+use std::{
+    io::{self, Write},
+    thread,
+};
+
+use tracing::{info_span, instrument};
+
+use aoc_utils::{
+    answer::Answer,
+    config, frames,
+    grid::Grid,
+    point::{Direction, Point},
+    term::{self, Style},
+};
+
+const DEBUG: bool = false;
+const DEBUG_WIDTH: usize = 200;
+const DEBUG_HEIGHT: usize = 45;
+const DEBUG_PADDING: (i64, i64) = (DEBUG_WIDTH as i64 / 6, DEBUG_HEIGHT as i64 / 6);
+const TEST_INPUT: InputKind = InputKind::Actual;
+
+#[allow(dead_code)]
+enum InputKind {
+    Test1,
+    Test2,
+    Actual,
+}
+
+pub const TITLE: &str = "Day 9";
+pub const INPUT: &str = match TEST_INPUT {
+    InputKind::Test1 => {
+        "R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2"
+    }
+    InputKind::Test2 => {
+        "R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20"
+    }
+    InputKind::Actual => include_str!("../inputs/day9.txt"),
+};
+
+pub fn solve(input: &str) -> [Answer; 2] {
+    let span = info_span!("parse").entered();
+    let input = parse(input);
+    span.exit();
+    [part1(&input).into(), part2(&input).into()]
+}
+
+fn parse(input: &str) -> Vec<(Direction, usize)> {
+    input
+        .lines()
+        .map(|line| {
+            let (direction, num) = line.split_once(' ').unwrap();
+            (
+                match direction {
+                    "U" => Direction::Up,
+                    "D" => Direction::Down,
+                    "L" => Direction::Left,
+                    "R" => Direction::Right,
+                    _ => panic!("invalid motion `{direction}`"),
+                },
+                num.parse().unwrap(),
+            )
+        })
+        .collect()
+}
+
+/// The cells visited by the tail, in a grid that grows whenever the head leaves it.
+struct Visited {
+    cells: Grid<bool>,
+    /// The position of the top left cell
+    origin: Point,
+}
+
+impl Visited {
+    fn new() -> Self {
+        Self {
+            cells: Grid::new(1, 1, true),
+            origin: Point::ORIGIN,
+        }
+    }
+
+    fn mark(&mut self, pos: Point) {
+        self.cells[pos - self.origin] = true;
+    }
+
+    fn count(&self) -> usize {
+        self.cells.iter().filter(|(_, cell)| **cell).count()
+    }
+}
+
+/// Moves the head one step into `direction`, growing `visited` to still include it.
+///
+/// Returns whether a new column or row was added at the front or back in x or y direction.
+fn do_motion(
+    direction: Direction,
+    positions: &mut [Point],
+    visited: &mut Visited,
+) -> (bool, bool, bool, bool) {
+    positions[0] += direction;
+    let head = positions[0] - visited.origin;
+    let new_front_x = head.x < 0;
+    let new_front_y = head.y < 0;
+    let new_back_x = head.x == visited.cells.width() as i64;
+    let new_back_y = head.y == visited.cells.height() as i64;
+    if new_front_x || new_front_y || new_back_x || new_back_y {
+        visited.cells.grow(
+            [new_front_x, new_front_y, new_back_x, new_back_y].map(usize::from),
+            false,
+        );
+        visited.origin -= Point::new(new_front_x.into(), new_front_y.into());
+    }
+    (new_front_x, new_front_y, new_back_x, new_back_y)
+}
+
+/// Moves each knot after the head towards its predecessor, if they are no longer touching.
+fn pull_knots(positions: &mut [Point]) {
+    for idx in 1..positions.len() {
+        let offset = positions[idx - 1] - positions[idx];
+        if offset.chebyshev(Point::ORIGIN) > 1 {
+            positions[idx] += offset.signum();
+        }
+    }
+}
+
+#[instrument(skip_all)]
+fn part1(input: &[(Direction, usize)]) -> usize {
+    simulate::<2>(input, false)
+}
+
+#[instrument(skip_all)]
+fn part2(input: &[(Direction, usize)]) -> usize {
+    simulate::<10>(input, DEBUG)
+}
+
+fn simulate<const KNOTS: usize>(input: &[(Direction, usize)], debug: bool) -> usize {
+    let mut visited = Visited::new();
+    let mut positions = [Point::ORIGIN; KNOTS];
+    if debug {
+        term::start_animation();
+    }
+    let show = debug || frames::watching();
+    let mut view = positions[0] - Point::new(DEBUG_WIDTH as i64 / 2, DEBUG_HEIGHT as i64 / 2);
+
+    for (direction, count) in input {
+        for _ in 0..*count {
+            let new_cells = do_motion(*direction, &mut positions, &mut visited);
+            pull_knots(&mut positions);
+            visited.mark(positions[KNOTS - 1]);
+
+            if show {
+                let head = positions[0] - view;
+                if head.x <= DEBUG_PADDING.0 {
+                    view.x -= 1;
+                } else if head.x >= DEBUG_WIDTH as i64 - DEBUG_PADDING.0 {
+                    view.x += 1;
+                } else if head.y <= DEBUG_PADDING.1 {
+                    view.y -= 1;
+                } else if head.y >= DEBUG_HEIGHT as i64 - DEBUG_PADDING.1 {
+                    view.y += 1;
+                }
+
+                let frame = Frame {
+                    positions: &positions,
+                    visited: &visited,
+                    new_cells,
+                    view,
+                    size: (DEBUG_WIDTH, DEBUG_HEIGHT),
+                };
+                if debug {
+                    let mut stdout = io::stdout().lock();
+                    term::next_frame(&mut stdout).unwrap();
+                    render_frame(&mut stdout, &frame, term::color()).unwrap();
+                }
+                frames::send(LEGEND, |out| render_frame(out, &frame, false));
+                thread::sleep(config::get().frame_delay());
+            }
+        }
+    }
+    if debug {
+        term::end_animation();
+    }
+    visited.count()
+}
+
+/// The colors of the glyphs of [`render_frame`] on the dashboard, the knots are numbered.
+const LEGEND: &[(char, &str)] = &[
+    ('H', "#43a047"),
+    ('1', "#eceff1"),
+    ('2', "#eceff1"),
+    ('3', "#eceff1"),
+    ('4', "#eceff1"),
+    ('5', "#eceff1"),
+    ('6', "#eceff1"),
+    ('7', "#eceff1"),
+    ('8', "#eceff1"),
+    ('9', "#eceff1"),
+    ('#', "#757575"),
+    ('+', "#2e7d32"),
+    ('.', "#263238"),
+    ('|', "#eceff1"),
+    ('-', "#eceff1"),
+];
+
+/// The state of the rope shown by one frame of the debug view.
+struct Frame<'a> {
+    positions: &'a [Point],
+    visited: &'a Visited,
+    /// Whether a new column or row was added at the front or back, as returned by [`do_motion`]
+    new_cells: (bool, bool, bool, bool),
+    /// The position of the top left corner of the view
+    view: Point,
+    size: (usize, usize),
+}
+
+fn render_frame(out: &mut impl Write, frame: &Frame, color: bool) -> io::Result<()> {
+    let Frame {
+        positions,
+        visited,
+        new_cells: (new_front_x, new_front_y, new_back_x, new_back_y),
+        view,
+        size: (width, height),
+    } = *frame;
+    let (cells_width, cells_height) = (visited.cells.width() as i64, visited.cells.height() as i64);
+    let paint = |style: Style, text: &str| style.paint(color, text);
+
+    let border = paint(Style::BOLD, &format!("+{}+", "-".repeat(width)));
+    writeln!(out, "{border}")?;
+
+    for y in 0..height as i64 {
+        write!(out, "{}", paint(Style::BOLD, "|"))?;
+        for x in 0..width as i64 {
+            let pos = view + Point::new(x, y);
+            let cell = pos - visited.origin;
+
+            match visited.cells.get(cell) {
+                Some(visited) => {
+                    match positions.iter().position(|knot| *knot == pos) {
+                        // bold green `H` for head
+                        Some(0) => write!(out, "{}", paint(Style::BOLD_GREEN, "H"))?,
+                        // bold white number
+                        Some(idx) => write!(out, "{}", paint(Style::BOLD, &idx.to_string()))?,
+                        None => match visited {
+                            // light gray `#` for visited cells
+                            true => write!(out, "{}", paint(Style::GRAY, "#"))?,
+                            // bold green `×` for new cells, `+` without color
+                            false
+                                if (new_front_x && cell.x == 0)
+                                    || (new_front_y && cell.y == 0)
+                                    || (new_back_x && cell.x == cells_width - 1)
+                                    || (new_back_y && cell.y == cells_height - 1) =>
+                            {
+                                write!(out, "{}", term::glyph(color, Style::BOLD_GREEN, "×", "+"))?
+                            }
+                            // dark gray `×` for everything else, `.` without color
+                            false => write!(out, "{}", term::glyph(color, Style::BLACK, "×", "."))?,
+                        },
+                    }
+                }
+                None if ((cell.x == -1 || cell.x == cells_width)
+                    && (0..cells_height).contains(&cell.y))
+                    || ((cell.y == -1 || cell.y == cells_height)
+                        && (-1..=cells_width).contains(&cell.x)) =>
+                {
+                    write!(out, "#")?
+                }
+                None => write!(out, " ")?,
+            }
+        }
+        writeln!(out, "{}", paint(Style::BOLD, "|"))?;
+    }
+
+    writeln!(out, "{border}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_renders;
+
+    #[test]
+    fn frame() {
+        let input = parse("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2");
+        let mut visited = Visited::new();
+        let mut positions = [Point::ORIGIN; 10];
+        let mut new_cells = (false, false, false, false);
+        for (direction, count) in &input {
+            for _ in 0..*count {
+                new_cells = do_motion(*direction, &mut positions, &mut visited);
+                pull_knots(&mut positions);
+                visited.mark(positions[9]);
+            }
+        }
+
+        let frame = Frame {
+            positions: &positions,
+            visited: &visited,
+            new_cells,
+            view: visited.origin - Point::new(3, 3),
+            size: (14, 10),
+        };
+        assert_renders("day9_frame", |color| {
+            let mut out = vec![];
+            render_frame(&mut out, &frame, color).unwrap();
+            String::from_utf8(out).unwrap()
+        });
+    }
+}
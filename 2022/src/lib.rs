@@ -0,0 +1,72 @@
+//! The days of Advent of Code 2022.
+
+// the lints and test helpers are only partially used if some days are left out
+#![cfg_attr(not(feature = "all"), allow(dead_code))]
+
+use aoc_utils::runner;
+
+pub mod lint;
+#[cfg(test)]
+mod test_utils;
+
+/// Declares the modules of the days and lists them in `DAYS`, each only if its `cfg` holds.
+macro_rules! run_days {
+    ($(#[$cfg:meta] $module:ident),* $(,)?) => {
+        $(
+            #[$cfg]
+            mod $module;
+        )*
+        /// All days enabled by cargo features, in the order they are run.
+        pub const DAYS: &[runner::Day] = &[
+            $(
+                #[$cfg]
+                runner::Day {
+                    name: stringify!($module),
+                    title: $module::TITLE,
+                    input: $module::INPUT,
+                    solve: &$module::solve,
+                    build_id: None,
+                },
+            )*
+        ];
+    };
+}
+
+run_days!(
+    #[cfg(feature = "day1")]
+    day1,
+    #[cfg(feature = "day2")]
+    day2,
+    #[cfg(feature = "day3")]
+    day3,
+    #[cfg(feature = "day4")]
+    day4,
+    #[cfg(feature = "day5")]
+    day5,
+    #[cfg(feature = "day6")]
+    day6,
+    #[cfg(feature = "day7")]
+    day7,
+    #[cfg(feature = "day7_alternative")]
+    day7_alternative,
+    #[cfg(feature = "day8")]
+    day8,
+    #[cfg(feature = "day9")]
+    day9,
+    #[cfg(feature = "day10")]
+    day10,
+    #[cfg(feature = "day11")]
+    day11,
+    #[cfg(feature = "day12")]
+    day12,
+    #[cfg(feature = "day12_bfs")]
+    day12_bfs,
+    #[cfg(feature = "day13")]
+    day13,
+    #[cfg(feature = "day14")]
+    day14,
+    #[cfg(feature = "day14_queue")]
+    day14_queue,
+    #[cfg(feature = "day15")]
+    day15,
+);
@@ -0,0 +1,658 @@
+use std::{fmt::Display, fs, num::ParseIntError};
+
+use itertools::Itertools;
+use regex::Regex;
+
+use aoc_utils::config;
+
+struct Problem {
+    line: usize,
+    message: String,
+}
+
+/// A puzzle input split into lines, collecting problems on the way.
+struct Lint<'a> {
+    lines: Vec<&'a str>,
+    problems: Vec<Problem>,
+}
+
+impl<'a> Lint<'a> {
+    fn new(input: &'a str) -> Self {
+        let mut lines = input.split('\n').collect_vec();
+        // a trailing newline does not start another line
+        if lines.last() == Some(&"") {
+            lines.pop();
+        }
+        Self {
+            // keep `\r` out of the grammar checks, it is reported separately
+            lines: lines
+                .into_iter()
+                .map(|line| line.strip_suffix('\r').unwrap_or(line))
+                .collect(),
+            problems: vec![],
+        }
+    }
+
+    fn report(&mut self, line: usize, message: impl Display) {
+        self.problems.push(Problem {
+            line: line + 1,
+            message: message.to_string(),
+        });
+    }
+
+    /// Reports a problem with the input as a whole at its last line.
+    fn report_end(&mut self, message: impl Display) {
+        self.report(self.lines.len().saturating_sub(1), message);
+    }
+
+    /// Checks every line in `range` against `rx` and returns the captures of the matching ones.
+    fn match_lines(
+        &mut self,
+        range: impl Iterator<Item = usize>,
+        rx: &Regex,
+        expected: &str,
+    ) -> Vec<(usize, Vec<&'a str>)> {
+        let mut matches = vec![];
+        for idx in range {
+            let line = self.lines[idx];
+            match rx.captures(line) {
+                Some(captures) => matches.push((
+                    idx,
+                    captures
+                        .iter()
+                        .skip(1)
+                        .map(|c| c.map_or("", |c| c.as_str()))
+                        .collect(),
+                )),
+                None => self.report(idx, format!("expected {expected}, found `{line}`")),
+            }
+        }
+        matches
+    }
+
+    /// Splits the lines into blocks separated by blank lines, returning the line ranges.
+    fn blocks(&self) -> Vec<(usize, usize)> {
+        let mut blocks = vec![];
+        let mut start = 0;
+        for (idx, line) in self.lines.iter().enumerate() {
+            if line.is_empty() {
+                blocks.push((start, idx));
+                start = idx + 1;
+            }
+        }
+        blocks.push((start, self.lines.len()));
+        blocks
+    }
+}
+
+fn rx(pattern: &str) -> Regex {
+    Regex::new(pattern).unwrap()
+}
+
+//////////////////////////////////////////////////////
+
+/// Checks for `\r\n` line endings and trailing whitespace.
+///
+/// If the input starts with a `padded` diagram, the lines up to the first blank line are skipped
+/// for the whitespace check, as they are filled up to a fixed width.
+fn check_whitespace(input: &str, padded: bool, lint: &mut Lint) {
+    let mut in_diagram = padded;
+    for (idx, line) in input.split('\n').enumerate() {
+        in_diagram &= !line.trim_end_matches('\r').is_empty();
+        if line.ends_with('\r') {
+            lint.report(idx, "line ends with CRLF");
+        } else if !in_diagram && line.ends_with(char::is_whitespace) {
+            lint.report(idx, "trailing whitespace");
+        }
+    }
+}
+
+fn day1(lint: &mut Lint) {
+    lint.match_lines(
+        0..lint.lines.len(),
+        &rx(r"^(?:\d+)?$"),
+        "a number or a blank line",
+    );
+}
+
+fn day2(lint: &mut Lint) {
+    lint.match_lines(0..lint.lines.len(), &rx(r"^[ABC] [XYZ]$"), "`[ABC] [XYZ]`");
+}
+
+fn day3(lint: &mut Lint) {
+    for (idx, [items]) in lint
+        .match_lines(
+            0..lint.lines.len(),
+            &rx(r"^([a-zA-Z]+)$"),
+            "a list of items",
+        )
+        .into_iter()
+        .map(|(idx, captures)| (idx, [captures[0]]))
+    {
+        if !items.len().is_multiple_of(2) {
+            lint.report(idx, "rucksack has an odd number of items");
+        }
+    }
+    if !lint.lines.len().is_multiple_of(3) {
+        lint.report_end("number of rucksacks is not a multiple of 3");
+    }
+}
+
+fn day4(lint: &mut Lint) {
+    for (idx, captures) in lint.match_lines(
+        0..lint.lines.len(),
+        &rx(r"^(\d+)-(\d+),(\d+)-(\d+)$"),
+        "`a-b,c-d`",
+    ) {
+        let Ok(numbers) = captures
+            .iter()
+            .map(|n| n.parse::<u8>())
+            .collect::<Result<Vec<_>, _>>()
+        else {
+            lint.report(idx, "section ID does not fit into `u8`");
+            continue;
+        };
+        if numbers[0] > numbers[1] || numbers[2] > numbers[3] {
+            lint.report(idx, "section range ends before it starts");
+        }
+    }
+}
+
+fn day5(lint: &mut Lint) {
+    const STACKS: usize = 9;
+    let Some(blank) = lint.lines.iter().position(|line| line.is_empty()) else {
+        lint.report_end("missing blank line between diagram and instructions");
+        return;
+    };
+    if blank == 0 {
+        lint.report(0, "missing stack diagram");
+        return;
+    }
+    let labels = lint.lines[blank - 1];
+    let columns = labels.split_whitespace().count();
+    if columns != STACKS {
+        lint.report(
+            blank - 1,
+            format!("expected {STACKS} stacks, found {columns}"),
+        );
+    }
+    if labels
+        .split_whitespace()
+        .ne((1..=columns).map(|n| n.to_string()))
+    {
+        lint.report(blank - 1, "stack labels are not numbered `1` to `n`");
+    }
+    for idx in 0..blank - 1 {
+        let line = lint.lines[idx];
+        if line.len() != 4 * STACKS - 1 {
+            lint.report(
+                idx,
+                format!(
+                    "diagram line is {} characters wide, expected {}",
+                    line.len(),
+                    4 * STACKS - 1
+                ),
+            );
+        }
+        for (col, cell) in line.as_bytes().chunks(4).enumerate() {
+            if !matches!(cell, [b' ', b' ', b' ', ..] | [b'[', b'A'..=b'Z', b']', ..]) {
+                lint.report(idx, format!("invalid crate in stack {}", col + 1));
+            }
+        }
+    }
+    for (idx, captures) in lint.match_lines(
+        blank + 1..lint.lines.len(),
+        &rx(r"^move (\d+) from (\d+) to (\d+)$"),
+        "`move n from a to b`",
+    ) {
+        for stack in &captures[1..] {
+            if !(1..=STACKS).contains(&stack.parse().unwrap_or(0)) {
+                lint.report(idx, format!("stack `{stack}` does not exist"));
+            }
+        }
+    }
+}
+
+fn day6(lint: &mut Lint) {
+    lint.match_lines(
+        0..lint.lines.len(),
+        &rx(r"^[a-z]+$"),
+        "a lowercase datastream",
+    );
+    if lint.lines.len() != 1 {
+        lint.report(0, format!("expected 1 line, found {}", lint.lines.len()));
+    }
+}
+
+fn day7(lint: &mut Lint) {
+    lint.match_lines(
+        0..lint.lines.len(),
+        &rx(r"^(?:\$ cd \S+|\$ ls|dir \S+|\d+ \S+)$"),
+        "`$ cd <dir>`, `$ ls`, `dir <name>` or `<size> <name>`",
+    );
+    if lint.lines.first() != Some(&"$ cd /") {
+        lint.report(0, "transcript does not start with `$ cd /`");
+    }
+}
+
+fn day8(lint: &mut Lint) {
+    lint.match_lines(0..lint.lines.len(), &rx(r"^\d+$"), "a row of tree heights");
+    check_rectangular(lint);
+}
+
+fn day9(lint: &mut Lint) {
+    lint.match_lines(0..lint.lines.len(), &rx(r"^[UDLR] \d+$"), "`[UDLR] n`");
+}
+
+fn day10(lint: &mut Lint) {
+    lint.match_lines(
+        0..lint.lines.len(),
+        &rx(r"^(?:noop|addx -?\d+)$"),
+        "`noop` or `addx n`",
+    );
+}
+
+/// Reuses the parser of the solver, so it is only available with its feature.
+#[cfg(feature = "day11")]
+fn day11(lint: &mut Lint) {
+    let mut numbers = vec![];
+    for (start, end) in lint.blocks() {
+        let block = lint.lines[start..end].join("\n");
+        match aoc_utils::parse::finish(&block, crate::day11::monkey) {
+            Ok(monke) => numbers.push((start, monke)),
+            Err(err) => lint.report(start + err.line - 1, err.message),
+        }
+    }
+    let count = numbers.len();
+    for (index, (line, monke)) in numbers.into_iter().enumerate() {
+        if monke.number != index {
+            lint.report(
+                line,
+                format!("expected monkey {index}, found monkey {}", monke.number),
+            );
+        }
+        for target in [monke.if_true, monke.if_false] {
+            if target >= count || target == monke.number {
+                lint.report(
+                    line,
+                    format!("monkey {} throws to monkey {target}", monke.number),
+                );
+            }
+        }
+    }
+}
+
+fn day12(lint: &mut Lint) {
+    lint.match_lines(0..lint.lines.len(), &rx(r"^[a-zSE]+$"), "a row of heights");
+    check_rectangular(lint);
+    for marker in ['S', 'E'] {
+        let found = lint
+            .lines
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, line)| line.matches(marker).map(move |_| idx))
+            .collect_vec();
+        match found[..] {
+            [_] => {}
+            [] => lint.report_end(format!("missing `{marker}`")),
+            [_, ref rest @ ..] => {
+                for idx in rest {
+                    lint.report(*idx, format!("more than one `{marker}`"));
+                }
+            }
+        }
+    }
+}
+
+/// Reuses the parser of the solver, so it is only available with its feature.
+#[cfg(feature = "day13")]
+fn day13(lint: &mut Lint) {
+    for (start, end) in lint.blocks() {
+        if end - start != 2 {
+            lint.report(
+                start,
+                format!("expected a pair of packets, found {}", end - start),
+            );
+        }
+        for idx in start..end {
+            if let Err(err) = aoc_utils::parse::finish(lint.lines[idx], crate::day13::value) {
+                lint.report(
+                    idx,
+                    format!("invalid packet at column {}: {}", err.column, err.message),
+                );
+            }
+        }
+    }
+}
+
+fn day14(lint: &mut Lint) {
+    for (idx, [path]) in lint
+        .match_lines(
+            0..lint.lines.len(),
+            &rx(r"^(\d+,\d+(?: -> \d+,\d+)*)$"),
+            "`x,y -> x,y -> ...`",
+        )
+        .into_iter()
+        .map(|(idx, captures)| (idx, [captures[0]]))
+    {
+        let Ok(knots) = path
+            .split(" -> ")
+            .map(|pos| {
+                let (x, y) = pos.split_once(',').unwrap();
+                Ok((x.parse::<u64>()?, y.parse::<u64>()?))
+            })
+            .collect::<Result<Vec<_>, ParseIntError>>()
+        else {
+            lint.report(idx, "coordinate does not fit into `u64`");
+            continue;
+        };
+        for (knot1, knot2) in knots.into_iter().tuple_windows() {
+            if knot1.0 != knot2.0 && knot1.1 != knot2.1 {
+                lint.report(
+                    idx,
+                    format!(
+                        "segment {},{} -> {},{} is not axis-aligned",
+                        knot1.0, knot1.1, knot2.0, knot2.1
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn day15(lint: &mut Lint) {
+    lint.match_lines(
+        0..lint.lines.len(),
+        &rx(r"^Sensor at x=-?\d+, y=-?\d+: closest beacon is at x=-?\d+, y=-?\d+$"),
+        "a sensor report",
+    );
+}
+
+/// Checks that all lines have the same length as the first one.
+fn check_rectangular(lint: &mut Lint) {
+    let Some(width) = lint.lines.first().map(|line| line.len()) else {
+        return;
+    };
+    for idx in 1..lint.lines.len() {
+        if lint.lines[idx].len() != width {
+            lint.report(
+                idx,
+                format!("row is {} wide, expected {width}", lint.lines[idx].len()),
+            );
+        }
+    }
+}
+
+//////////////////////////////////////////////////////
+
+type Grammar = fn(&mut Lint);
+
+/// The grammar of each day's input and whether it starts with a padded diagram.
+const DAYS: &[(&str, Grammar, bool)] = &[
+    ("day1", day1, false),
+    ("day2", day2, false),
+    ("day3", day3, false),
+    ("day4", day4, false),
+    ("day5", day5, true),
+    ("day6", day6, false),
+    ("day7", day7, false),
+    ("day8", day8, false),
+    ("day9", day9, false),
+    ("day10", day10, false),
+    #[cfg(feature = "day11")]
+    ("day11", day11, false),
+    ("day12", day12, false),
+    #[cfg(feature = "day13")]
+    ("day13", day13, false),
+    ("day14", day14, false),
+    ("day15", day15, false),
+];
+
+/// The days whose grammar reuses the parser of the solver, and so needs the feature of the day.
+const PARSER_GRAMMARS: &[&str] = &["day11", "day13"];
+
+/// Checks `input` against `grammar`, returning the problems in the order of their lines.
+fn check(input: &str, grammar: Grammar, padded: bool) -> Vec<Problem> {
+    let mut lint = Lint::new(input);
+    check_whitespace(input, padded, &mut lint);
+    grammar(&mut lint);
+    lint.problems.sort_by_key(|problem| problem.line);
+    lint.problems
+}
+
+/// Lints the inputs of the given days, e.g. `day5` or `5`, or all days if `days` is empty.
+///
+/// Returns whether all inputs are valid and all days are known.
+pub fn main(days: &[String]) -> bool {
+    let days = days
+        .iter()
+        .map(|day| match day.starts_with("day") {
+            true => day.clone(),
+            false => format!("day{day}"),
+        })
+        .collect_vec();
+    let mut valid = true;
+    for day in &days {
+        if DAYS.iter().any(|&(name, _, _)| name == day) {
+            continue;
+        }
+        match PARSER_GRAMMARS.contains(&day.as_str()) {
+            true => {
+                eprintln!("`{day}` is not built with this feature set, enable `{day}` to lint it")
+            }
+            false => eprintln!("unknown day `{day}`"),
+        }
+        valid = false;
+    }
+    for &(name, grammar, padded) in DAYS {
+        if !days.is_empty() && !days.iter().any(|day| day == name) {
+            continue;
+        }
+        let path = config::get().input_path(name).display().to_string();
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{path}: {err}");
+                valid = false;
+                continue;
+            }
+        };
+        let problems = check(&input, grammar, padded);
+        for Problem { line, message } in &problems {
+            println!("{path}:{line}: {message}");
+        }
+        valid &= problems.is_empty();
+    }
+    valid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The problems of `input` for the day called `name`, as `(line, message)`.
+    fn problems(name: &str, input: &str) -> Vec<(usize, String)> {
+        let &(_, grammar, padded) = DAYS.iter().find(|(day, _, _)| *day == name).unwrap();
+        check(input, grammar, padded)
+            .into_iter()
+            .map(|Problem { line, message }| (line, message))
+            .collect()
+    }
+
+    fn assert_problems(name: &str, input: &str, expected: &[(usize, &str)]) {
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|&(line, message)| (line, message.to_owned()))
+            .collect();
+        assert_eq!(problems(name, input), expected);
+    }
+
+    #[test]
+    fn reports_crlf_and_trailing_whitespace() {
+        assert_problems(
+            "day1",
+            "1\r\n2 \n\n3\n",
+            &[
+                (1, "line ends with CRLF"),
+                (2, "trailing whitespace"),
+                (2, "expected a number or a blank line, found `2 `"),
+            ],
+        );
+    }
+
+    #[test]
+    fn reports_lines_not_matching_the_grammar() {
+        assert_problems(
+            "day2",
+            "A X\nD Y\n",
+            &[(2, "expected `[ABC] [XYZ]`, found `D Y`")],
+        );
+        assert_problems(
+            "day10",
+            "noop\naddx\n",
+            &[(2, "expected `noop` or `addx n`, found `addx`")],
+        );
+    }
+
+    #[test]
+    fn reports_rucksacks() {
+        assert_problems(
+            "day3",
+            "abc\nabcd\n",
+            &[
+                (1, "rucksack has an odd number of items"),
+                (2, "number of rucksacks is not a multiple of 3"),
+            ],
+        );
+    }
+
+    #[test]
+    fn reports_section_ranges() {
+        assert_problems(
+            "day4",
+            "1-2,3-4\n5-4,1-1\n1-300,1-2\n",
+            &[
+                (2, "section range ends before it starts"),
+                (3, "section ID does not fit into `u8`"),
+            ],
+        );
+    }
+
+    #[test]
+    fn reports_misaligned_stacks() {
+        let stacks = "[A]".to_owned() + &" ".repeat(32);
+        let labels = " 1   2   3   4   5   6   7   8   9 ";
+        let valid = format!("{stacks}\n{labels}\n\nmove 1 from 1 to 2\n");
+        assert_problems("day5", &valid, &[]);
+
+        let misaligned = format!(" {stacks}\n{labels}\n\nmove 1 from 1 to 10\n");
+        assert_problems(
+            "day5",
+            &misaligned,
+            &[
+                (1, "diagram line is 36 characters wide, expected 35"),
+                (1, "invalid crate in stack 1"),
+                (4, "stack `10` does not exist"),
+            ],
+        );
+        assert_problems(
+            "day5",
+            "move 1 from 1 to 2\n",
+            &[(1, "missing blank line between diagram and instructions")],
+        );
+    }
+
+    #[test]
+    fn reports_datastream_and_transcript() {
+        assert_problems("day6", "abc\ndef\n", &[(1, "expected 1 line, found 2")]);
+        assert_problems(
+            "day7",
+            "$ ls\n12 a.txt\n",
+            &[(1, "transcript does not start with `$ cd /`")],
+        );
+    }
+
+    #[test]
+    fn reports_ragged_rows() {
+        assert_problems(
+            "day8",
+            "123\n45\n678\n",
+            &[(2, "row is 2 wide, expected 3")],
+        );
+    }
+
+    #[test]
+    fn reports_motions() {
+        assert_problems(
+            "day9",
+            "R 4\nX 1\n",
+            &[(2, "expected `[UDLR] n`, found `X 1`")],
+        );
+    }
+
+    #[test]
+    fn reports_start_and_end_counts() {
+        assert_problems("day12", "SabE\nabcd\n", &[]);
+        assert_problems(
+            "day12",
+            "SabS\nabcd\n",
+            &[(1, "more than one `S`"), (2, "missing `E`")],
+        );
+    }
+
+    #[cfg(feature = "day11")]
+    #[test]
+    fn reports_monkey_order_and_targets() {
+        let monkey = |number, if_true, if_false| {
+            format!(
+                "Monkey {number}:\n  Starting items: 79, 98\n  Operation: new = old * 19\n  \
+                 Test: divisible by 23\n    If true: throw to monkey {if_true}\n    \
+                 If false: throw to monkey {if_false}\n"
+            )
+        };
+        let input = [monkey(0, 1, 1), monkey(2, 0, 2)].join("\n");
+        assert_problems(
+            "day11",
+            &input,
+            &[
+                (8, "expected monkey 1, found monkey 2"),
+                (8, "monkey 2 throws to monkey 2"),
+            ],
+        );
+    }
+
+    #[cfg(feature = "day13")]
+    #[test]
+    fn reports_packets() {
+        let problems = problems("day13", "[1,2]\n[3\n\n[]\n");
+        assert_eq!(problems.len(), 2, "{problems:?}");
+        assert!(problems[0].0 == 2 && problems[0].1.starts_with("invalid packet"));
+        assert_eq!(
+            problems[1],
+            (4, "expected a pair of packets, found 1".to_owned())
+        );
+    }
+
+    #[test]
+    fn reports_diagonal_segments() {
+        assert_problems(
+            "day14",
+            "498,4 -> 498,6 -> 496,6\n503,4 -> 502,5\n",
+            &[(2, "segment 503,4 -> 502,5 is not axis-aligned")],
+        );
+        assert_problems(
+            "day14",
+            "1,2 -> 1,99999999999999999999999\n",
+            &[(1, "coordinate does not fit into `u64`")],
+        );
+    }
+
+    #[test]
+    fn reports_sensors() {
+        assert_problems(
+            "day15",
+            "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=2\n",
+            &[(2, "expected a sensor report, found `Sensor at x=2`")],
+        );
+    }
+}
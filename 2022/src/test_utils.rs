@@ -0,0 +1,12 @@
+/// Snapshots the output of a renderer both with and without color codes.
+///
+/// In the colored snapshot, escape characters are written as `\x1b` to keep the file readable.
+pub fn assert_renders(name: &str, render: impl Fn(bool) -> String) {
+    insta::with_settings!({ prepend_module_to_snapshot => false }, {
+        insta::assert_snapshot!(
+            format!("{name}_color"),
+            render(true).replace('\x1b', "\\x1b")
+        );
+        insta::assert_snapshot!(format!("{name}_plain"), render(false));
+    });
+}
@@ -0,0 +1,134 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Set to also write the generated header to `include/aoc.h`, which is otherwise left untouched.
+const UPDATE_HEADER: &str = "AOC_UPDATE_HEADER";
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed={UPDATE_HEADER}");
+
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let config = cbindgen::Config::from_file(format!("{crate_dir}/cbindgen.toml"))
+        .expect("cbindgen.toml is valid");
+    let header = cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(format!("{crate_dir}/src/ffi.rs"))
+        .generate()
+        .expect("the C interface can be expressed in C");
+    // the checked-in header is compared with this one by `tests/ffi.rs`
+    header.write_to_file(PathBuf::from(env::var("OUT_DIR").unwrap()).join("aoc.h"));
+    if env::var_os(UPDATE_HEADER).is_some() {
+        header.write_to_file(format!("{crate_dir}/include/aoc.h"));
+    }
+
+    if env::var_os("CARGO_FEATURE_PRECOMPUTED").is_some() {
+        precompute(Path::new(&crate_dir));
+    }
+}
+
+/// The days enabled by cargo features, e.g. `day7_alternative`.
+fn enabled_days() -> Vec<String> {
+    let mut days: Vec<_> = env::vars()
+        .filter_map(|(key, _)| Some(key.strip_prefix("CARGO_FEATURE_")?.to_lowercase()))
+        .filter(|feature| feature.starts_with("day"))
+        .collect();
+    days.sort();
+    days
+}
+
+/// The years of the workspace, each in the directory named after it.
+fn years(crate_dir: &Path) -> Vec<String> {
+    let mut years: Vec<_> = fs::read_dir(crate_dir)
+        .unwrap()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| {
+            name.parse::<u16>().is_ok() && crate_dir.join(name).join("Cargo.toml").exists()
+        })
+        .collect();
+    years.sort();
+    years
+}
+
+/// Builds the enabled days without `precomputed`, runs those of each year with `--json` and writes
+/// their answers to `precomputed.rs` in `OUT_DIR`, to be included by `src/precomputed.rs`.
+///
+/// The days are solved for their embedded inputs with the default configuration, so that neither
+/// `aoc.toml` nor plugins end up in the binary. Each entry is keyed by the year, the name and the
+/// `answer_key` of the input and parameters, and is only used when these are the same at runtime.
+fn precompute(crate_dir: &Path) {
+    let years = years(crate_dir);
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=utils/src");
+    for year in &years {
+        println!("cargo:rerun-if-changed={year}/src");
+        println!("cargo:rerun-if-changed={year}/inputs");
+    }
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    // an empty file rather than a missing one, which could be created in the meantime
+    let config = out_dir.join("precompute.toml");
+    fs::write(&config, "").unwrap();
+    let days = enabled_days();
+
+    let mut entries = String::new();
+    for year in &years {
+        let mut cargo = Command::new(env::var("CARGO").unwrap());
+        // the features of this build would be inherited by the build script of the nested one,
+        // which would then precompute again
+        for (key, _) in env::vars_os() {
+            if key.to_string_lossy().starts_with("CARGO_FEATURE_") {
+                cargo.env_remove(key);
+            }
+        }
+        // a target directory of its own, as the one of this build is locked
+        let output = cargo
+            .args(["run", "--quiet", "--release", "--no-default-features"])
+            .args(["--features", &days.join(",")])
+            .arg("--manifest-path")
+            .arg(crate_dir.join("Cargo.toml"))
+            .arg("--target-dir")
+            .arg(out_dir.join("precompute"))
+            .args(["--", "--json", "--color", "never", "--no-plugins"])
+            .args(["--year", year, "--config"])
+            .arg(&config)
+            .current_dir(crate_dir)
+            .output()
+            .expect("could not run cargo");
+        // failing days are reported in the output and left out
+        if !output.status.success() && output.stdout.is_empty() {
+            panic!(
+                "could not solve the days of {year}:\n{}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        for line in String::from_utf8(output.stdout).unwrap().lines() {
+            let result: serde_json::Value =
+                serde_json::from_str(line).expect("runner printed JSON");
+            let name = result["name"].as_str().expect("result has a name");
+            match (result["error"].as_str(), result["key"].as_str()) {
+                (Some(error), _) => {
+                    println!("cargo:warning=not precomputing {year} {name}, it failed: {error}")
+                }
+                (None, key) => entries.push_str(&format!(
+                    "    ({year}, {name:?}, 0x{}, {:?}),\n",
+                    key.expect("solved days have a key"),
+                    result["answers"].to_string()
+                )),
+            }
+        }
+    }
+    fs::write(
+        out_dir.join("precomputed.rs"),
+        format!(
+            "/// The year, name and `answer_key` of each day and its answers as written by\n\
+            /// `Answer::to_json`.\n\
+            const ANSWERS: &[(u16, &str, u64, &str)] = &[\n{entries}];\n"
+        ),
+    )
+    .unwrap();
+}
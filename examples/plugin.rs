@@ -0,0 +1,25 @@
+//! A day built as a plugin, see `aoc::plugin`. Build it with
+//! `cargo build --release --example plugin` and copy the library from `target/release/examples`
+//! into the directory of the year it belongs to, e.g. `plugins/2022`.
+
+aoc::export_plugin! {
+    name: "day0",
+    title: "Day 0: Plugin Example",
+    input: "3\n1 4\n1 5 9\n",
+    solve: solve,
+}
+
+/// Counts the numbers on each line, then sums the largest number of each line.
+fn solve(input: &str) -> (usize, u32) {
+    let lines: Vec<Vec<u32>> = input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|number| number.parse().expect("invalid number"))
+                .collect()
+        })
+        .collect();
+    let count = lines.iter().map(Vec::len).sum();
+    let largest = lines.iter().filter_map(|line| line.iter().max()).sum();
+    (count, largest)
+}
@@ -0,0 +1,64 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated from src/ffi.rs by build.rs, do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The result of [`aoc_solve`].
+typedef enum AocStatus {
+  AOC_STATUS_OK = 0,
+  // A pointer argument was null
+  AOC_STATUS_NULL_POINTER = 1,
+  // The year or the day is not compiled into the library
+  AOC_STATUS_UNKNOWN_DAY = 2,
+  // The part is neither 1 nor 2
+  AOC_STATUS_INVALID_PART = 3,
+  // The input is not valid UTF-8
+  AOC_STATUS_INVALID_INPUT = 4,
+  // The solver panicked, the message is written to the buffer if it fits
+  AOC_STATUS_SOLVER_FAILED = 5,
+  // The buffer is too small, its `len` is set to the length needed
+  AOC_STATUS_BUFFER_TOO_SMALL = 6,
+} AocStatus;
+
+// A buffer owned by the caller that receives a NUL-terminated UTF-8 string.
+typedef struct AocBuffer {
+  char *ptr;
+  // The number of bytes `ptr` points to, including space for the NUL terminator
+  size_t capacity;
+  // The length of the string written, or needed, without the NUL terminator
+  size_t len;
+} AocBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Solves `part` of the day of `year` called `day`, e.g. `"7"` or `"7_alternative"`, for the `len`
+// bytes of UTF-8 text at `input`, and writes the answer to `out`.
+//
+// Both parts are solved, so callers that need both should keep the input around rather than
+// expect the second call to be cheaper. Panics of the solver are caught and reported as
+// [`AocStatus::SolverFailed`].
+//
+// # Safety
+//
+// `day` must be a NUL-terminated string, `input` must point to `len` readable bytes and `out`
+// must point to an [`AocBuffer`] whose `ptr` has `capacity` writable bytes.
+enum AocStatus aoc_solve(uint16_t year,
+                         const char *day,
+                         uint32_t part,
+                         const uint8_t *input,
+                         size_t len,
+                         struct AocBuffer *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC_H */
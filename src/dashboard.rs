@@ -0,0 +1,189 @@
+use std::{
+    borrow::Cow,
+    io::Write,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use aoc_utils::{
+    config, frames,
+    runner::{self, Day, Outcome},
+};
+
+/// The page of the dashboard, which draws the frames it receives from `/events`.
+const PAGE: &str = include_str!("dashboard.html");
+
+/// The days that send frames to the dashboard.
+const VISUALIZED: &[&str] = &[
+    "day9",
+    "day10",
+    "day12",
+    "day12_bfs",
+    "day14",
+    "day14_queue",
+];
+
+/// The open `/events` streams, each receiving complete Server-Sent Events.
+type Clients = Arc<Mutex<Vec<Sender<String>>>>;
+
+fn broadcast(clients: &Clients, event: &str, data: &Value) {
+    let message = format!("event: {event}\ndata: {data}\n\n");
+    // the streams of clients that disconnected are gone
+    clients
+        .lock()
+        .unwrap()
+        .retain(|client| client.send(message.clone()).is_ok());
+}
+
+/// Sends all events to the client of `request` until it disconnects.
+fn stream(request: Request, clients: &Clients) {
+    let (sender, receiver) = mpsc::channel();
+    clients.lock().unwrap().push(sender);
+    // tiny_http buffers response bodies, so the response is written by hand to flush every event
+    let mut writer = request.into_writer();
+    let head = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\n\
+        Connection: close\r\n\r\n";
+    for message in [head.to_owned()].into_iter().chain(receiver) {
+        if writer
+            .write_all(message.as_bytes())
+            .and_then(|()| writer.flush())
+            .is_err()
+        {
+            return;
+        }
+    }
+}
+
+/// Clears the flag of the running day when dropped by the thread solving it, which may keep
+/// running and sending frames after its timeout if it does not check for cancellation.
+struct Running(Arc<AtomicBool>);
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Solves `day` on its own thread, announcing it with a `start` event and its answers with a
+/// `finish` event. The frames are sent in between as `frame` events.
+///
+/// No other day can be started until the solver has actually stopped, even after a timeout.
+fn start(
+    day: &'static Day,
+    input: Cow<'static, str>,
+    timeout: Option<Duration>,
+    clients: Clients,
+    running: Arc<AtomicBool>,
+) {
+    thread::spawn(move || {
+        broadcast(
+            &clients,
+            "start",
+            &json!({ "name": day.name, "title": day.title }),
+        );
+        let solve = day.solve;
+        let running = Running(running);
+        let solve = move || {
+            let _running = running;
+            solve(&input)
+        };
+        let (answers, error) = match runner::run_with_timeout(solve, timeout) {
+            Outcome::Finished(answers) => (json!(answers.map(|answer| answer.to_json())), None),
+            Outcome::Failed(failure) => (Value::Null, Some(failure.to_string())),
+            Outcome::TimedOut(timeout) => (
+                Value::Null,
+                Some(format!("exceeded {timeout:?}, running until it stops")),
+            ),
+        };
+        broadcast(
+            &clients,
+            "finish",
+            &json!({ "name": day.name, "answers": answers, "error": error }),
+        );
+    });
+}
+
+fn error(status: u16, message: impl ToString) -> (u16, &'static str, String) {
+    let body = json!({ "error": message.to_string() });
+    (status, "application/json", body.to_string())
+}
+
+fn handle(
+    request: &Request,
+    days: &'static [Day],
+    timeout: Option<Duration>,
+    clients: &Clients,
+    running: &Arc<AtomicBool>,
+) -> (u16, &'static str, String) {
+    let visualized = || days.iter().filter(|day| VISUALIZED.contains(&day.name));
+    let segments: Vec<_> = request.url().trim_matches('/').split('/').collect();
+    match (request.method(), &segments[..]) {
+        (Method::Get, [""]) => (200, "text/html; charset=utf-8", PAGE.to_owned()),
+        (Method::Get, ["days"]) => {
+            let days: Value = visualized()
+                .map(|day| json!({ "name": day.name, "title": day.title }))
+                .collect();
+            (200, "application/json", days.to_string())
+        }
+        (Method::Post, ["run", day]) => {
+            let name = format!("day{day}");
+            let Some(day) = visualized().find(|day| day.name == name) else {
+                return error(404, format!("no visualization of day `{day}`"));
+            };
+            let input = match config::get().input(day) {
+                Ok(input) => input,
+                Err(err) => return error(500, err),
+            };
+            if running.swap(true, Ordering::SeqCst) {
+                return error(409, "another day is still running");
+            }
+            start(day, input, timeout, clients.clone(), running.clone());
+            let body = json!({ "name": day.name, "title": day.title });
+            (202, "application/json", body.to_string())
+        }
+        (_, [""] | ["days"] | ["run", _]) => error(405, "method not allowed"),
+        _ => error(404, format!("unknown endpoint `{}`", request.url())),
+    }
+}
+
+/// Serves the dashboard from `server` until it is closed.
+///
+/// The page at `/` lists the visualized `days`, starts them with `POST /run/{n}` and draws the
+/// frames streamed from `/events` as Server-Sent Events. Only one day runs at a time.
+pub fn run(server: &Server, days: &'static [Day], timeout: Option<Duration>) {
+    let clients = Clients::default();
+    let running = Arc::new(AtomicBool::new(false));
+    let frames = frames::watch();
+    let frame_clients = clients.clone();
+    thread::spawn(move || {
+        for frame in frames {
+            broadcast(&frame_clients, "frame", &frame.to_json());
+        }
+    });
+
+    for request in server.incoming_requests() {
+        if (request.method(), request.url()) == (&Method::Get, "/events") {
+            let clients = clients.clone();
+            thread::spawn(move || stream(request, &clients));
+            continue;
+        }
+        let (status, content_type, body) = handle(&request, days, timeout, &clients, &running);
+        let response = Response::from_string(body)
+            .with_status_code(status)
+            .with_header(
+                format!("Content-Type: {content_type}")
+                    .parse::<Header>()
+                    .expect("header is valid"),
+            );
+        // the client may have disconnected already, there is nobody to report this to
+        let _ = request.respond(response);
+    }
+}
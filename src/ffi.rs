@@ -0,0 +1,123 @@
+//! The C interface of the solvers, declared in `include/aoc.h`, which is generated from this file
+//! by the build script. After changing the interface, update it by building with
+//! `AOC_UPDATE_HEADER=1`.
+
+use std::{
+    ffi::{c_char, CStr},
+    ptr, slice,
+};
+
+use aoc_utils::runner::{self, Day, Outcome};
+
+/// The result of [`aoc_solve`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    Ok = 0,
+    /// A pointer argument was null
+    NullPointer = 1,
+    /// The year or the day is not compiled into the library
+    UnknownDay = 2,
+    /// The part is neither 1 nor 2
+    InvalidPart = 3,
+    /// The input is not valid UTF-8
+    InvalidInput = 4,
+    /// The solver panicked, the message is written to the buffer if it fits
+    SolverFailed = 5,
+    /// The buffer is too small, its `len` is set to the length needed
+    BufferTooSmall = 6,
+}
+
+/// A buffer owned by the caller that receives a NUL-terminated UTF-8 string.
+#[repr(C)]
+pub struct AocBuffer {
+    pub ptr: *mut c_char,
+    /// The number of bytes `ptr` points to, including space for the NUL terminator
+    pub capacity: usize,
+    /// The length of the string written, or needed, without the NUL terminator
+    pub len: usize,
+}
+
+impl AocBuffer {
+    /// Writes `text` if it fits, otherwise only records how much space it needs.
+    fn write(&mut self, text: &str) -> bool {
+        self.len = text.len();
+        if text.len() >= self.capacity {
+            return false;
+        }
+        // SAFETY: the caller guarantees that `ptr` points to `capacity` writable bytes
+        unsafe {
+            ptr::copy_nonoverlapping(text.as_ptr(), self.ptr.cast(), text.len());
+            *self.ptr.add(text.len()) = 0;
+        }
+        true
+    }
+}
+
+/// Finds a day of `year` by its name, e.g. `day7`, `7` or `7_alternative`.
+fn find_day(year: u16, name: &str) -> Option<&'static Day> {
+    let name = name.strip_prefix("day").unwrap_or(name);
+    crate::year(year)?
+        .days
+        .iter()
+        .find(|day| day.name.strip_prefix("day") == Some(name))
+}
+
+/// Solves `part` of the day of `year` called `day`, e.g. `"7"` or `"7_alternative"`, for the `len`
+/// bytes of UTF-8 text at `input`, and writes the answer to `out`.
+///
+/// Both parts are solved, so callers that need both should keep the input around rather than
+/// expect the second call to be cheaper. Panics of the solver are caught and reported as
+/// [`AocStatus::SolverFailed`].
+///
+/// # Safety
+///
+/// `day` must be a NUL-terminated string, `input` must point to `len` readable bytes and `out`
+/// must point to an [`AocBuffer`] whose `ptr` has `capacity` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    year: u16,
+    day: *const c_char,
+    part: u32,
+    input: *const u8,
+    len: usize,
+    out: *mut AocBuffer,
+) -> AocStatus {
+    if day.is_null() || (input.is_null() && len > 0) || out.is_null() {
+        return AocStatus::NullPointer;
+    }
+    let out = &mut *out;
+    if out.ptr.is_null() && out.capacity > 0 {
+        return AocStatus::NullPointer;
+    }
+    let Some(day) = CStr::from_ptr(day)
+        .to_str()
+        .ok()
+        .and_then(|day| find_day(year, day))
+    else {
+        return AocStatus::UnknownDay;
+    };
+    if !matches!(part, 1 | 2) {
+        return AocStatus::InvalidPart;
+    }
+    let input = match len {
+        0 => "",
+        _ => match std::str::from_utf8(slice::from_raw_parts(input, len)) {
+            Ok(input) => input,
+            Err(_) => return AocStatus::InvalidInput,
+        },
+    };
+
+    let solve = day.solve;
+    let input = input.to_owned();
+    // the solvers run on their own thread, which also keeps panics from unwinding into C
+    let (text, status) = match runner::run_with_timeout(move || solve(&input), None) {
+        Outcome::Finished(answers) => (answers[part as usize - 1].to_string(), AocStatus::Ok),
+        Outcome::Failed(failure) => (failure.to_string(), AocStatus::SolverFailed),
+        Outcome::TimedOut(_) => unreachable!("there is no timeout"),
+    };
+    match (out.write(&text), status) {
+        (false, AocStatus::Ok) => AocStatus::BufferTooSmall,
+        (_, status) => status,
+    }
+}
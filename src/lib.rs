@@ -0,0 +1,39 @@
+//! The runner of all years and its tools. The helpers shared by the days are in `aoc_utils`, the
+//! days of each year in the crate named after it, e.g. `aoc_2022`.
+
+use aoc_utils::runner::Day;
+
+pub mod dashboard;
+pub mod ffi;
+pub mod minimize;
+pub mod plugin;
+#[cfg(feature = "precomputed")]
+pub mod precomputed;
+pub mod report;
+pub mod server;
+pub mod watch;
+
+/// The days of one year and where its crate lives.
+pub struct Year {
+    pub year: u16,
+    /// The days enabled by cargo features, in the order they are run
+    pub days: &'static [Day],
+    /// The directory of its crate relative to the workspace, with the sources in `src` and the
+    /// inputs in `inputs`
+    pub dir: &'static str,
+    /// Lints the inputs of the given days, or all days if there are none
+    pub lint: fn(&[String]) -> bool,
+}
+
+/// All years, the last one is run by default.
+pub const YEARS: &[Year] = &[Year {
+    year: 2022,
+    days: aoc_2022::DAYS,
+    dir: "2022",
+    lint: aoc_2022::lint::main,
+}];
+
+/// Finds the year `year`.
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|other| other.year == year)
+}
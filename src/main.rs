@@ -1,37 +1,267 @@
-use std::time::Instant;
-
-macro_rules! run_days {
-    ($($module:ident),* $(,)?) => {
-        $(mod $module;)*
-        fn main() {
-            let start_total = Instant::now();
-            $(
-                let start = Instant::now();
-                $module::main();
-                println!("\x1b[90m{:?}\x1b[0m\n", start.elapsed());
-            )*
-            println!("\x1b[1mTotal: {:?}\x1b[0m", start_total.elapsed());
-        }
+use std::{
+    io::{self, IsTerminal},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+use clap::{ArgAction, Parser, Subcommand};
+use tracing_subscriber::{
+    filter, fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer,
+};
+
+#[cfg(feature = "precomputed")]
+use aoc::precomputed;
+use aoc::{dashboard, minimize, plugin, report, server, watch, YEARS};
+#[cfg(not(feature = "precomputed"))]
+use aoc_utils::cache::Cache;
+use aoc_utils::{
+    allocations, config, runner,
+    term::{self, ColorMode},
+    timing::PhaseLayer,
+};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// The year whose days to run (default the latest one)
+    #[arg(long, global = true)]
+    year: Option<u16>,
+    /// Only run this day, e.g. `day7` or `7_alternative`, can be given more than once
+    #[arg(long = "day", value_name = "DAY")]
+    days: Vec<String>,
+    /// Do not load the days in the plugin directory
+    #[arg(long)]
+    no_plugins: bool,
+    /// Stop each day after this many seconds and report it as timed out
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+    /// Read the configuration from this file instead of `aoc.toml`
+    #[arg(long, value_name = "PATH", default_value = config::PATH, global = true)]
+    config: PathBuf,
+    /// Solve each day this many times and report the mean time (default from the configuration)
+    #[arg(long, value_name = "COUNT")]
+    repeat: Option<u32>,
+    /// Log how the answers are reached to stderr, `-vv` for even more detail (overrides `RUST_LOG`)
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Solve all days again instead of reusing the answers in the cache
+    #[cfg(not(feature = "precomputed"))]
+    #[arg(long)]
+    no_cache: bool,
+    /// Print one JSON object per day instead of the answers and timings, used by `watch`
+    #[arg(long, hide = true)]
+    json: bool,
+    /// Whether to color the output (default from the configuration)
+    #[arg(long, value_name = "WHEN", global = true)]
+    color: Option<ColorMode>,
+    /// Solve the days again and compare the answers with the ones found when building
+    #[cfg(feature = "precomputed")]
+    #[arg(long)]
+    verify: bool,
+}
+
+fn parse_seconds(arg: &str) -> Result<Duration, String> {
+    Duration::try_from_secs_f64(arg.parse().map_err(|err| format!("{err}"))?)
+        .map_err(|err| err.to_string())
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Validate the puzzle inputs of the year without solving them
+    Lint {
+        /// Only lint these days, e.g. `day5`
+        days: Vec<String>,
+    },
+    /// Shrink an input that makes a day fail, printing the smallest one that still fails
+    Minimize {
+        /// The day that fails, e.g. `day7`
+        day: String,
+        /// The file containing the failing input
+        input: PathBuf,
+        #[command(subcommand)]
+        predicate: minimize::Predicate,
+    },
+    /// Write a Markdown table of the answers, timings and allocations of all days
+    Report {
+        /// The file to write the table to
+        #[arg(long, default_value = "REPORT.md")]
+        output: PathBuf,
+        /// The accepted answers to check the days against
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+    },
+    /// Rebuild and rerun a day whenever its source, its input or the configuration changes
+    Watch {
+        /// The day to run, e.g. `7`, `day7` or `7_alternative`
+        day: String,
+    },
+    /// Serve the solvers over HTTP, taking puzzle inputs as request bodies
+    Serve {
+        /// The address to listen on
+        #[arg(long, default_value = "127.0.0.1:8080")]
+        addr: String,
+    },
+    /// Serve a page that draws the visualizations of the days while they are solved
+    Dashboard {
+        /// The address to listen on
+        #[arg(long, default_value = "127.0.0.1:8081")]
+        addr: String,
+    },
+}
+
+#[global_allocator]
+static ALLOCATOR: allocations::CountingAllocator = allocations::CountingAllocator;
+
+/// Logs spans and events to stderr, filtered by `RUST_LOG` unless `-v` is given.
+///
+/// Independently of the filter, all spans are passed to the [`PhaseLayer`] to time the phases.
+fn init_tracing(verbose: u8, color: ColorMode) {
+    let filter = match verbose {
+        0 => EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")),
+        1 => EnvFilter::new("debug"),
+        _ => EnvFilter::new("trace"),
     };
+    let log = fmt::layer()
+        .with_writer(io::stderr)
+        .with_ansi(color.enabled(io::stderr().is_terminal()))
+        .without_time()
+        .with_filter(filter);
+    let phases = PhaseLayer.with_filter(filter::filter_fn(|metadata| metadata.is_span()));
+    tracing_subscriber::registry().with(log).with(phases).init();
 }
 
-run_days!(
-    day1,
-    day2,
-    day3,
-    day4,
-    day5,
-    day6,
-    day7,
-    day7_alternative,
-    day8,
-    day9,
-    day10,
-    day11,
-    day12,
-    day12_bfs,
-    day13,
-    day14,
-    // day14_queue,
-    day15,
-);
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let year = match cli.year {
+        None => YEARS.last().expect("there is a year"),
+        Some(year) => match aoc::year(year) {
+            Some(year) => year,
+            None => {
+                let years: Vec<_> = YEARS.iter().map(|year| year.year.to_string()).collect();
+                eprintln!("unknown year {year}, available: {}", years.join(", "));
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    let mut config = match config::load(&cli.config) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("invalid configuration: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    config.year_input_dir = Path::new(year.dir).join("inputs");
+    if let Some(repeat) = cli.repeat {
+        config.repeat = repeat;
+    }
+    if let Some(color) = cli.color {
+        config.color = color;
+    }
+    init_tracing(cli.verbose, config.color);
+    term::init(config.color);
+    config::init(config);
+    // plugins are listed after the built-in days and can be used wherever those are
+    let mut days = year.days.to_vec();
+    if !cli.no_plugins {
+        let dir = config::get().plugin_dir.join(year.year.to_string());
+        days.extend(plugin::load_dir(&dir, year.days));
+    }
+    if !cli.days.is_empty() {
+        let names: Vec<_> = cli
+            .days
+            .iter()
+            .map(|day| match day.starts_with("day") {
+                true => day.clone(),
+                false => format!("day{day}"),
+            })
+            .collect();
+        if let Some(unknown) = names
+            .iter()
+            .find(|name| !days.iter().any(|day| day.name == **name))
+        {
+            eprintln!("unknown day `{unknown}`");
+            return ExitCode::FAILURE;
+        }
+        days.retain(|day| names.contains(&day.name.to_owned()));
+    }
+    let days: &'static [runner::Day] = days.leak();
+    match cli.command {
+        None if cli.json => {
+            if !runner::run_json(days, config::get(), cli.timeout) {
+                return ExitCode::FAILURE;
+            }
+        }
+        #[cfg(feature = "precomputed")]
+        None => {
+            if !precomputed::run(year.year, days, config::get(), cli.timeout, cli.verify) {
+                return ExitCode::FAILURE;
+            }
+        }
+        #[cfg(not(feature = "precomputed"))]
+        None => {
+            let config = config::get();
+            let cache =
+                (!cli.no_cache).then(|| Cache::new(&config.cache_dir.join(year.year.to_string())));
+            if !runner::run(days, config, cli.timeout, cache.as_ref()) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Some(Command::Lint { days }) => {
+            if !(year.lint)(&days) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Some(Command::Minimize {
+            day,
+            input,
+            predicate,
+        }) => {
+            if !minimize::main(days, &day, &input, predicate, cli.timeout) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Some(Command::Report { output, answers }) => {
+            if !report::main(
+                year.year,
+                days,
+                config::get(),
+                cli.timeout,
+                &answers,
+                &output,
+            ) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Some(Command::Watch { day }) => {
+            if !watch::main(year, &day, &cli.config, cli.timeout) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Some(Command::Serve { addr }) => {
+            let server = match tiny_http::Server::http(&addr) {
+                Ok(server) => server,
+                Err(err) => {
+                    eprintln!("could not listen on {addr}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            println!("Listening on http://{addr}");
+            server::run(&server, days, cli.timeout);
+        }
+        Some(Command::Dashboard { addr }) => {
+            let server = match tiny_http::Server::http(&addr) {
+                Ok(server) => server,
+                Err(err) => {
+                    eprintln!("could not listen on {addr}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            println!("Dashboard at http://{addr}");
+            dashboard::run(&server, days, cli.timeout);
+        }
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,406 @@
+use std::{
+    cell::Cell,
+    fs,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use clap::Subcommand;
+use tracing::debug;
+
+use aoc_utils::{
+    answer::Answer,
+    runner::{self, Day, Outcome},
+};
+
+/// How long a candidate input may run if no `--timeout` is given, as removing parts of an input
+/// can easily send a solver into an endless loop.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long a timed-out candidate may take to reach a checkpoint and stop.
+const CANCEL_GRACE: Duration = Duration::from_secs(1);
+
+/// What makes an input fail.
+#[derive(Subcommand)]
+pub enum Predicate {
+    /// The solver panics at the same location as with the original input
+    Panic {
+        /// Only count panics whose message contains this text
+        #[arg(long)]
+        message: Option<String>,
+    },
+    /// The solver and another variant of the day give different answers
+    Mismatch {
+        /// The variant to compare with, e.g. `day7_alternative`
+        variant: String,
+    },
+    /// The answers differ from the output of a reference solution
+    Reference {
+        /// The command to run, which reads the input from stdin and prints one answer per line
+        #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        command: Vec<String>,
+    },
+}
+
+/// A way to cut an input into pieces that can be left out independently.
+#[derive(Debug, Clone, Copy)]
+enum Level {
+    /// Paragraphs separated by blank lines
+    Blocks,
+    Lines,
+    /// Shell commands of day 7, each with its output
+    Commands,
+    /// Items within a line, e.g. the knots of a rock path of day 14
+    Items(&'static str),
+}
+
+impl Level {
+    fn split(self, input: &str) -> Vec<&str> {
+        match self {
+            Level::Blocks => input.split("\n\n").collect(),
+            Level::Lines => input.split('\n').collect(),
+            Level::Commands => {
+                let mut commands = vec![];
+                let mut start = 0;
+                for (idx, _) in input.match_indices("\n$ ") {
+                    commands.push(&input[start..idx]);
+                    start = idx + 1;
+                }
+                commands.push(&input[start..]);
+                commands
+            }
+            Level::Items(separator) => input.split(separator).collect(),
+        }
+    }
+
+    fn separator(self) -> &'static str {
+        match self {
+            Level::Blocks => "\n\n",
+            Level::Lines | Level::Commands => "\n",
+            Level::Items(separator) => separator,
+        }
+    }
+}
+
+/// The levels at which the input of the day called `name` is reduced, from coarse to fine.
+fn levels(name: &str) -> &'static [Level] {
+    // variants share the input format of their day
+    match name.split('_').next().unwrap_or(name) {
+        "day1" | "day11" | "day13" => &[Level::Blocks, Level::Lines],
+        "day7" => &[Level::Commands, Level::Lines],
+        "day14" => &[Level::Lines, Level::Items(" -> ")],
+        // one instruction, row or sensor per line
+        _ => &[Level::Lines],
+    }
+}
+
+/// Removes as many `items` as possible while `test` still holds for the rest.
+///
+/// This is the ddmin algorithm of Zeller and Hildebrandt, only trying complements: the items are
+/// split into `n` chunks and each chunk is left out in turn. If that keeps the failure, the search
+/// continues with the smaller list, otherwise with twice as many chunks.
+fn ddmin<T: Clone>(mut items: Vec<T>, mut test: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut chunks = 2;
+    while !items.is_empty() {
+        chunks = chunks.min(items.len());
+        let chunk_len = items.len().div_ceil(chunks);
+        let reduced = (0..items.len()).step_by(chunk_len).find_map(|start| {
+            let mut candidate = items.clone();
+            candidate.drain(start..(start + chunk_len).min(items.len()));
+            test(&candidate).then_some(candidate)
+        });
+        match reduced {
+            Some(candidate) => {
+                items = candidate;
+                chunks = (chunks - 1).max(2);
+            }
+            None if chunks == items.len() => break,
+            None => chunks *= 2,
+        }
+    }
+    items
+}
+
+/// Reduces `input` at each of the `levels` in turn, keeping only what `test` needs.
+///
+/// The pieces left at one level are reduced one by one at the finer levels.
+fn reduce(input: &str, levels: &[Level], test: &mut dyn FnMut(&str) -> bool) -> String {
+    let Some((level, finer)) = levels.split_first() else {
+        return input.to_owned();
+    };
+    let separator = level.separator();
+    let pieces = level.split(input).into_iter().map(str::to_owned).collect();
+    let mut pieces = ddmin(pieces, |pieces| test(&pieces.join(separator)));
+    for idx in 0..pieces.len() {
+        let piece = reduce(&pieces[idx], finer, &mut |piece| {
+            let mut candidate = pieces.clone();
+            candidate[idx] = piece.to_owned();
+            test(&candidate.join(separator))
+        });
+        pieces[idx] = piece;
+    }
+    pieces.join(separator)
+}
+
+/// Sets its flag when dropped by the thread solving a candidate, once the solver has stopped.
+struct Stopped(Arc<AtomicBool>);
+
+impl Drop for Stopped {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Decides whether a candidate input still fails like the original one.
+struct Check<'a> {
+    day: &'a Day,
+    predicate: Predicate,
+    /// The other variant, for [`Predicate::Mismatch`]
+    variant: Option<&'a Day>,
+    /// Where the original input made the solver panic, for [`Predicate::Panic`]
+    location: Option<String>,
+    timeout: Duration,
+    runs: usize,
+    /// Whether a candidate timed out and did not stop when cancelled. Its thread keeps running, so
+    /// no further candidates are tried rather than leaving one such thread behind for each.
+    runaway: Cell<bool>,
+}
+
+impl Check<'_> {
+    fn solve(&self, day: &Day, input: &str) -> Outcome<[Answer; 2]> {
+        let solve = day.solve;
+        let input = input.to_owned();
+        let stopped = Arc::new(AtomicBool::new(false));
+        let guard = Stopped(stopped.clone());
+        let outcome = runner::run_with_timeout(
+            move || {
+                let _guard = guard;
+                solve(&input)
+            },
+            Some(self.timeout),
+        );
+        if let Outcome::TimedOut(_) = outcome {
+            let deadline = Instant::now() + CANCEL_GRACE;
+            while !stopped.load(Ordering::SeqCst) && Instant::now() < deadline {
+                thread::sleep(Duration::from_millis(10));
+            }
+            self.runaway
+                .set(self.runaway.get() || !stopped.load(Ordering::SeqCst));
+        }
+        outcome
+    }
+
+    fn fails(&mut self, input: &str) -> bool {
+        if self.runaway.get() {
+            return false;
+        }
+        self.runs += 1;
+        let fails = match &self.predicate {
+            Predicate::Panic { message } => match self.solve(self.day, input) {
+                Outcome::Failed(failure) => {
+                    failure.location() == self.location.as_deref()
+                        && message
+                            .as_ref()
+                            .is_none_or(|message| failure.to_string().contains(message))
+                }
+                _ => false,
+            },
+            Predicate::Mismatch { .. } => {
+                let variant = self.variant.expect("variant was looked up");
+                match (self.solve(self.day, input), self.solve(variant, input)) {
+                    (Outcome::Finished(answers), Outcome::Finished(expected)) => {
+                        answers != expected
+                    }
+                    _ => false,
+                }
+            }
+            Predicate::Reference { command } => match self.solve(self.day, input) {
+                Outcome::Finished(answers) => match reference(command, input) {
+                    Some(expected) => expected != answers.map(|answer| answer.to_string()),
+                    None => false,
+                },
+                _ => false,
+            },
+        };
+        debug!(len = input.len(), fails, "checked candidate");
+        fails
+    }
+}
+
+/// Runs the reference solution on `input`, returning `None` if it rejects it or cannot be run.
+fn reference(command: &[String], input: &str) -> Option<[String; 2]> {
+    let mut child = match Command::new(&command[0])
+        .args(&command[1..])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(err) => {
+            eprintln!("could not run `{}`: {err}", command[0]);
+            return None;
+        }
+    };
+    // the reference may exit without reading everything
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let _ = stdin.write_all(input.as_bytes());
+    drop(stdin);
+    let output = child.wait_with_output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let mut lines = stdout.lines().map(|line| line.trim().to_owned());
+    Some([lines.next()?, lines.next()?])
+}
+
+/// Shrinks the input at `path` for the day called `name` while it keeps failing as described by
+/// `predicate`, and prints the result.
+///
+/// Returns whether the input could be minimized, which stops early if a candidate times out and
+/// cannot be cancelled.
+pub fn main(
+    days: &[Day],
+    name: &str,
+    path: &Path,
+    predicate: Predicate,
+    timeout: Option<Duration>,
+) -> bool {
+    let find = |name: &str| days.iter().find(|day| day.name == name);
+    let Some(day) = find(name) else {
+        eprintln!("unknown day `{name}`");
+        return false;
+    };
+    let variant = match &predicate {
+        Predicate::Mismatch { variant } => match find(variant) {
+            Some(variant) => Some(variant),
+            None => {
+                eprintln!("unknown day `{variant}`");
+                return false;
+            }
+        },
+        _ => None,
+    };
+    let input = match fs::read_to_string(path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("could not read {}: {err}", path.display());
+            return false;
+        }
+    };
+    let mut check = Check {
+        day,
+        predicate,
+        variant,
+        location: None,
+        timeout: timeout.unwrap_or(DEFAULT_TIMEOUT),
+        runs: 0,
+        runaway: Cell::new(false),
+    };
+    if let Predicate::Panic { .. } = check.predicate {
+        match check.solve(day, &input) {
+            Outcome::Failed(failure) => check.location = failure.location().map(str::to_owned),
+            _ => {
+                eprintln!("{}: the solver does not panic", path.display());
+                return false;
+            }
+        }
+    }
+    if !check.fails(&input) {
+        eprintln!(
+            "{}: the input does not reproduce the failure",
+            path.display()
+        );
+        return false;
+    }
+
+    // keep a trailing newline out of the pieces, but pass it to the solvers like the original
+    let newline = if input.ends_with('\n') { "\n" } else { "" };
+    let minimized = reduce(
+        input.trim_end_matches('\n'),
+        levels(name),
+        &mut |candidate| check.fails(&format!("{candidate}{newline}")),
+    );
+    print!("{minimized}{newline}");
+    eprintln!(
+        "reduced {} to {} lines in {} runs",
+        input.lines().count(),
+        minimized.lines().count(),
+        check.runs
+    );
+    if check.runaway.get() {
+        eprintln!(
+            "stopped early, a candidate exceeded {:?} and did not stop when cancelled",
+            check.timeout
+        );
+    }
+    !check.runaway.get()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ddmin_keeps_what_the_test_needs() {
+        let items = (0..20).collect();
+        let minimal = ddmin(items, |items| items.contains(&3) && items.contains(&17));
+        assert_eq!(minimal, [3, 17]);
+        assert_eq!(ddmin(vec![1, 2], |_| true), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn reduces_day7_commands_and_day14_paths() {
+        let transcript = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n$ cd a\n$ ls\n29116 f";
+        let minimal = reduce(transcript, levels("day7_alternative"), &mut |input| {
+            input.contains("$ cd a") && input.contains("29116 f")
+        });
+        assert_eq!(minimal, "$ cd a\n29116 f");
+
+        let paths = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9";
+        let minimal = reduce(paths, levels("day14"), &mut |input| input.contains("502,9"));
+        assert_eq!(minimal, "502,9");
+    }
+
+    #[test]
+    fn stops_after_a_candidate_that_cannot_be_cancelled() {
+        let day = Day {
+            name: "day0",
+            title: "Day 0",
+            input: "",
+            solve: &|input| match input {
+                "hang" => loop {
+                    thread::sleep(Duration::from_millis(10));
+                },
+                _ => panic!("broken"),
+            },
+            build_id: None,
+        };
+        let mut check = Check {
+            day: &day,
+            predicate: Predicate::Panic { message: None },
+            variant: None,
+            location: None,
+            timeout: Duration::from_millis(10),
+            runs: 0,
+            runaway: Cell::new(false),
+        };
+        check.location = match check.solve(&day, "") {
+            Outcome::Failed(failure) => failure.location().map(str::to_owned),
+            _ => unreachable!(),
+        };
+        assert!(check.fails("1"));
+        assert!(!check.fails("hang"));
+        assert!(check.runaway.get());
+        assert!(!check.fails("1"));
+        assert_eq!(check.runs, 2);
+    }
+}
@@ -0,0 +1,318 @@
+//! Days built as shared libraries and loaded at runtime from the subdirectory of the plugin
+//! directory named after their year, e.g. `plugins/2022`.
+//!
+//! A plugin exports [`REGISTER_SYMBOL`], usually through [`export_plugin!`](crate::export_plugin),
+//! and everything crossing the boundary uses the C ABI. Each plugin has its own copy of the
+//! statics of this crate though, so the configuration, the phase timings and cancellation are not
+//! shared: plugins are only timed as a whole and keep running in the background after a timeout.
+
+use std::{
+    ffi::{c_char, c_void, CStr},
+    fmt::Display,
+    fs, io, panic,
+    path::Path,
+    slice, str,
+};
+
+use libloading::Library;
+
+use aoc_utils::{
+    answer::Answer,
+    cache,
+    runner::{self, Day},
+};
+
+use crate::ffi::AocStatus;
+
+/// The version of [`Registration`], bumped on every incompatible change.
+pub const ABI_VERSION: u32 = 1;
+
+/// The name of the [`Register`] function a plugin exports.
+pub const REGISTER_SYMBOL: &str = "aoc_plugin_register";
+
+/// Returns the day of a plugin, which must live as long as the plugin is loaded.
+pub type Register = unsafe extern "C" fn() -> *const Registration;
+
+/// Receives the answer to `part`, or the failure message as part `0`, as `len` bytes of UTF-8.
+pub type Emit = unsafe extern "C" fn(sink: *mut c_void, part: u32, text: *const u8, len: usize);
+
+/// Solves both parts of `len` bytes of UTF-8 input, passing the answers to `emit` with `sink`.
+pub type Solve =
+    unsafe extern "C" fn(input: *const u8, len: usize, sink: *mut c_void, emit: Emit) -> AocStatus;
+
+/// The day of a plugin. Only `abi_version` may be read before it is known to be [`ABI_VERSION`].
+#[repr(C)]
+pub struct Registration {
+    pub abi_version: u32,
+    /// The NUL-terminated name, e.g. `day16`
+    pub name: *const c_char,
+    /// The NUL-terminated title
+    pub title: *const c_char,
+    /// The embedded input of `input_len` bytes
+    pub input: *const u8,
+    pub input_len: usize,
+    pub solve: Solve,
+}
+
+// SAFETY: registrations are never modified and only point to statics
+unsafe impl Sync for Registration {}
+
+/// Exports a day from a `cdylib` crate as a plugin, e.g.
+///
+/// ```ignore
+/// aoc::export_plugin! {
+///     name: "day16",
+///     title: "Day 16: Proboscidea Volcanium",
+///     input: include_str!("../inputs/day16.txt"),
+///     solve: solve,
+/// }
+/// ```
+///
+/// where `solve` takes the input and returns a tuple of both answers.
+#[macro_export]
+macro_rules! export_plugin {
+    (name: $name:literal, title: $title:literal, input: $input:expr, solve: $solve:path $(,)?) => {
+        #[no_mangle]
+        pub extern "C" fn aoc_plugin_register() -> *const $crate::plugin::Registration {
+            const INPUT: &str = $input;
+            static REGISTRATION: $crate::plugin::Registration = $crate::plugin::Registration {
+                abi_version: $crate::plugin::ABI_VERSION,
+                name: concat!($name, "\0").as_ptr().cast(),
+                title: concat!($title, "\0").as_ptr().cast(),
+                input: INPUT.as_ptr(),
+                input_len: INPUT.len(),
+                solve: solve_exported,
+            };
+
+            unsafe extern "C" fn solve_exported(
+                input: *const u8,
+                len: usize,
+                sink: *mut ::std::ffi::c_void,
+                emit: $crate::plugin::Emit,
+            ) -> $crate::ffi::AocStatus {
+                $crate::plugin::solve_exported(input, len, sink, emit, $solve)
+            }
+
+            &REGISTRATION
+        }
+    };
+}
+
+/// Reads `len` bytes of UTF-8 at `ptr`, which may be null if there are none.
+unsafe fn text<'a>(ptr: *const u8, len: usize) -> Result<&'a str, str::Utf8Error> {
+    match len {
+        0 => Ok(""),
+        _ => str::from_utf8(slice::from_raw_parts(ptr, len)),
+    }
+}
+
+/// The [`Solve`] of [`export_plugin!`](crate::export_plugin), catching panics of `solve` so that
+/// they do not unwind into the runner.
+///
+/// # Safety
+///
+/// `input` must point to `len` readable bytes, and `emit` must accept `sink`.
+pub unsafe fn solve_exported<A: Display, B: Display>(
+    input: *const u8,
+    len: usize,
+    sink: *mut c_void,
+    emit: Emit,
+    solve: fn(&str) -> (A, B),
+) -> AocStatus {
+    let Ok(input) = text(input, len) else {
+        return AocStatus::InvalidInput;
+    };
+    let (texts, status) = match runner::isolated(|| {
+        let (part1, part2) = solve(input);
+        [part1.to_string(), part2.to_string()]
+    }) {
+        Ok([part1, part2]) => (vec![(1, part1), (2, part2)], AocStatus::Ok),
+        Err(failure) => (vec![(0, failure.to_string())], AocStatus::SolverFailed),
+    };
+    for (part, text) in texts {
+        emit(sink, part, text.as_ptr(), text.len());
+    }
+    status
+}
+
+/// Collects the texts passed to [`Emit`] into the `[String; 3]` at `sink`.
+unsafe extern "C" fn collect(sink: *mut c_void, part: u32, text: *const u8, len: usize) {
+    let texts = &mut *sink.cast::<[String; 3]>();
+    if let Some(slot) = texts.get_mut(part as usize) {
+        *slot = String::from_utf8_lossy(slice::from_raw_parts(text, len)).into_owned();
+    }
+}
+
+/// Calls the `solve` of a plugin, turning its failures into panics like those of built-in days.
+fn call(solve: Solve, input: &str) -> [Answer; 2] {
+    let mut texts: [String; 3] = Default::default();
+    // SAFETY: the input is valid UTF-8 and `collect` expects the texts
+    let status = unsafe {
+        solve(
+            input.as_ptr(),
+            input.len(),
+            (&mut texts as *mut [String; 3]).cast(),
+            collect,
+        )
+    };
+    let [message, part1, part2] = texts;
+    match status {
+        AocStatus::Ok => [part1, part2].map(|text| match text.parse() {
+            Ok(integer) => Answer::Integer(integer),
+            Err(_) => Answer::Text(text),
+        }),
+        // the message already contains the location in the plugin
+        AocStatus::SolverFailed => panic::resume_unwind(Box::new(message)),
+        status => panic::resume_unwind(Box::new(format!("plugin returned {status:?}"))),
+    }
+}
+
+/// Turns a registration into a day, checking its version first.
+///
+/// # Safety
+///
+/// `registration` must be null or point to a registration that is never unloaded, whose fields
+/// are valid if its version is [`ABI_VERSION`].
+pub unsafe fn register(registration: *const Registration) -> Result<Day, String> {
+    if registration.is_null() {
+        return Err("registered no day".to_owned());
+    }
+    let version = (*registration).abi_version;
+    if version != ABI_VERSION {
+        return Err(format!(
+            "built for plugin ABI version {version}, but the runner supports version {ABI_VERSION}"
+        ));
+    }
+    let registration = &*registration;
+    let c_text = |ptr: *const c_char, field| match ptr.is_null() {
+        true => Err(format!("its {field} is missing")),
+        false => CStr::from_ptr(ptr)
+            .to_str()
+            .map_err(|_| format!("its {field} is not valid UTF-8")),
+    };
+    let name = c_text(registration.name, "name")?;
+    if !name.starts_with("day") {
+        return Err(format!("its name `{name}` does not start with `day`"));
+    }
+    let input = text(registration.input, registration.input_len)
+        .map_err(|_| "its input is not valid UTF-8".to_owned())?;
+    let solve = registration.solve;
+    Ok(Day {
+        name,
+        title: c_text(registration.title, "title")?,
+        input,
+        solve: Box::leak(Box::new(move |input: &str| call(solve, input))),
+        build_id: None,
+    })
+}
+
+/// Loads the plugin at `path`, which then stays loaded until the program exits.
+///
+/// The day is identified by the contents of the library, so that its cached answers are not used
+/// once it is rebuilt.
+pub fn load(path: &Path) -> Result<Day, String> {
+    let build_id = cache::file_id(path).ok_or("could not be read")?;
+    // SAFETY: loading runs the initializers of the library, plugins are trusted like the runner
+    let library = unsafe { Library::new(path) }.map_err(|err| err.to_string())?;
+    let library: &'static Library = Box::leak(Box::new(library));
+    // SAFETY: the symbol is checked to be a `Register` by the version in the registration
+    let register_fn = unsafe { library.get::<Register>(REGISTER_SYMBOL.as_bytes()) }
+        .map_err(|_| format!("does not export `{REGISTER_SYMBOL}`"))?;
+    // SAFETY: the library is never unloaded
+    let day = unsafe { register(register_fn()) }?;
+    Ok(Day {
+        build_id: Some(build_id),
+        ..day
+    })
+}
+
+/// Loads the plugins in `dir`, skipping with a warning those that fail to load or whose day is
+/// already in `known`. A missing directory has no plugins.
+pub fn load_dir(dir: &Path, known: &[Day]) -> Vec<Day> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Vec::new(),
+        Err(err) => {
+            eprintln!("could not read plugins in {}: {err}", dir.display());
+            return Vec::new();
+        }
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == std::env::consts::DLL_EXTENSION)
+        })
+        .collect();
+    paths.sort();
+
+    let mut days: Vec<Day> = Vec::new();
+    for path in paths {
+        let day = load(&path).and_then(|day| {
+            match known
+                .iter()
+                .chain(&days)
+                .any(|other| other.name == day.name)
+            {
+                true => Err(format!("day `{}` is already known", day.name)),
+                false => Ok(day),
+            }
+        });
+        match day {
+            Ok(day) => days.push(day),
+            Err(err) => eprintln!("skipping plugin {}: {err}", path.display()),
+        }
+    }
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ptr;
+
+    unsafe extern "C" fn solve(
+        input: *const u8,
+        len: usize,
+        sink: *mut c_void,
+        emit: Emit,
+    ) -> AocStatus {
+        solve_exported(input, len, sink, emit, |input| match input {
+            "" => panic!("no input"),
+            _ => (input.len(), input.to_uppercase()),
+        })
+    }
+
+    fn registration(abi_version: u32) -> Registration {
+        Registration {
+            abi_version,
+            name: c"day0".as_ptr(),
+            title: c"Day 0".as_ptr(),
+            input: ptr::null(),
+            input_len: 0,
+            solve,
+        }
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let registration = registration(ABI_VERSION + 1);
+        let err = unsafe { register(&registration) }.err().unwrap();
+        assert!(err.contains("plugin ABI version 2"), "{err}");
+    }
+
+    #[test]
+    fn solves_through_the_abi() {
+        let registration = Box::leak(Box::new(registration(ABI_VERSION)));
+        let day = unsafe { register(registration) }.unwrap();
+        assert_eq!((day.name, day.title, day.input), ("day0", "Day 0", ""));
+        assert_eq!(
+            (day.solve)("abc"),
+            [Answer::Integer(3), Answer::Text("ABC".to_owned())]
+        );
+        let failure = runner::isolated(|| (day.solve)("")).err().unwrap();
+        assert!(failure.to_string().starts_with("no input at "), "{failure}");
+        assert_eq!(failure.location(), None);
+    }
+}
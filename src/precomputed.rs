@@ -0,0 +1,109 @@
+//! Answers found by the build script for the embedded inputs and the default parameters, see
+//! `precompute` in `build.rs`.
+
+use std::time::Duration;
+
+use aoc_utils::{
+    answer::Answer,
+    cache,
+    config::Config,
+    runner::{self, Day, Outcome},
+    term::{self, Style},
+};
+
+include!(concat!(env!("OUT_DIR"), "/precomputed.rs"));
+
+/// The answers of `day` of `year`, if the build script solved it for the same input and parameters
+/// as given by `config`.
+pub fn answers(year: u16, day: &Day, config: &Config) -> Option<[Answer; 2]> {
+    let key = cache::answer_key(day, &config.input(day).ok()?, config);
+    let (.., answers) = ANSWERS.iter().find(|&&(other_year, name, other_key, _)| {
+        (other_year, name, other_key) == (year, day.name, key)
+    })?;
+    let answers: Vec<_> = serde_json::from_str::<Vec<_>>(answers)
+        .ok()?
+        .iter()
+        .map(Answer::from_json)
+        .collect::<Option<_>>()?;
+    answers.try_into().ok()
+}
+
+/// Prints the precomputed answers of all `days` of `year`. Days without any, like those of plugins
+/// or those run with other inputs or parameters, are solved instead.
+///
+/// With `verify`, every day is solved again and its answers are compared with the precomputed ones.
+/// Returns whether all days have answers and, when verifying, whether they all match.
+pub fn run(
+    year: u16,
+    days: &[Day],
+    config: &Config,
+    timeout: Option<Duration>,
+    verify: bool,
+) -> bool {
+    let color = term::color();
+    let mut failed = vec![];
+    for day in days {
+        println!("--- {} ---", day.title);
+        let precomputed = answers(year, day, config);
+        if let (Some(answers), false) = (&precomputed, verify) {
+            for (part, answer) in answers.iter().enumerate() {
+                println!("Part {}: {}", part + 1, answer.render(color));
+            }
+            println!("{}\n", Style::GRAY.paint(color, "precomputed"));
+            continue;
+        }
+
+        let solution = match runner::solve(day, config, timeout) {
+            Outcome::Finished(solution) => solution,
+            Outcome::Failed(failure) => {
+                println!("{}: {failure}\n", Style::BOLD_RED.paint(color, "FAILED"));
+                failed.push(format!("{} (failed)", day.name));
+                continue;
+            }
+            Outcome::TimedOut(timeout) => {
+                let message = format!("exceeded {timeout:?}");
+                println!(
+                    "{}: {message}\n",
+                    Style::BOLD_YELLOW.paint(color, "TIMEOUT")
+                );
+                failed.push(format!("{} (timeout)", day.name));
+                continue;
+            }
+        };
+        let mut mismatched = false;
+        for (part, answer) in solution.answers.iter().enumerate() {
+            match precomputed.as_ref().map(|answers| &answers[part]) {
+                Some(expected) if expected != answer => {
+                    mismatched = true;
+                    println!(
+                        "Part {}: {} (precomputed {expected})",
+                        part + 1,
+                        Style::BOLD_RED.paint(color, answer.render(color))
+                    );
+                }
+                _ => println!("Part {}: {}", part + 1, answer.render(color)),
+            }
+        }
+        let status = match (&precomputed, mismatched) {
+            (None, _) => "solved, not precomputed",
+            (Some(_), false) => "verified",
+            (Some(_), true) => {
+                failed.push(format!("{} (mismatch)", day.name));
+                "does not match the precomputed answers"
+            }
+        };
+        let status = format!("{status} in {:.1?}", solution.phases.total);
+        println!("{}\n", Style::GRAY.paint(color, status));
+    }
+    if !failed.is_empty() {
+        println!(
+            "{} {}",
+            Style::BOLD_RED.paint(
+                color,
+                format!("{} of {} days failed:", failed.len(), days.len())
+            ),
+            failed.join(", ")
+        );
+    }
+    failed.is_empty()
+}
@@ -0,0 +1,194 @@
+use std::{fmt::Write, fs, io, path::Path, time::Duration};
+
+use aoc_utils::{
+    answer::Answer,
+    config::Config,
+    runner::{self, Day, Outcome, Solution},
+};
+
+/// Reads the accepted answers of `year`, with one `[<year>.dayN]` table of `part1` and `part2` per
+/// day.
+fn load_answers(path: &Path, year: u16) -> Result<toml::Table, String> {
+    let mut answers: toml::Table = match fs::read_to_string(path) {
+        Ok(text) => toml::from_str(&text).map_err(|err| format!("{}: {err}", path.display()))?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(toml::Table::new()),
+        Err(err) => return Err(format!("{}: {err}", path.display())),
+    };
+    match answers.remove(&year.to_string()) {
+        None => Ok(toml::Table::new()),
+        Some(toml::Value::Table(answers)) => Ok(answers),
+        Some(_) => Err(format!("{}: `{year}` is not a table", path.display())),
+    }
+}
+
+/// Compares `answers` with the accepted ones of the day called `name`, if there are any.
+fn status(name: &str, answers: &[Answer; 2], accepted: &toml::Table) -> Option<String> {
+    // variants share the answers of their day
+    let accepted = accepted.get(name.split('_').next().unwrap_or(name))?;
+    let wrong: Vec<_> = answers
+        .iter()
+        .zip(["part1", "part2"])
+        .filter(|(answer, part)| {
+            let expected = match accepted.get(part) {
+                Some(toml::Value::String(text)) => text.clone(),
+                Some(value) => value.to_string(),
+                None => return false,
+            };
+            answer.to_string() != expected
+        })
+        .map(|(_, part)| part)
+        .collect();
+    Some(match wrong[..] {
+        [] => "pass".to_owned(),
+        _ => format!("**wrong {}**", wrong.join(", ")),
+    })
+}
+
+/// Makes `text` fit into a cell of a Markdown table.
+fn cell(text: impl ToString) -> String {
+    text.to_string().replace('|', "\\|").replace('\n', "<br>")
+}
+
+fn duration(duration: Duration) -> String {
+    format!("{duration:.1?}")
+}
+
+/// Solves all `days` of `year` and writes a Markdown table of their answers, timings, allocations
+/// and whether the answers match the ones at `answers_path` to `output`.
+///
+/// Returns whether all days finished with the accepted answers.
+pub fn main(
+    year: u16,
+    days: &[Day],
+    config: &Config,
+    timeout: Option<Duration>,
+    answers_path: &Path,
+    output: &Path,
+) -> bool {
+    let accepted = match load_answers(answers_path, year) {
+        Ok(accepted) => accepted,
+        Err(err) => {
+            eprintln!("invalid answers: {err}");
+            return false;
+        }
+    };
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    let runs = match config.repeat.max(1) {
+        1 => "a single run".to_owned(),
+        repeat => format!("the mean of {repeat} runs"),
+    };
+
+    let mut report = String::new();
+    writeln!(report, "# Results of {year}\n").unwrap();
+    writeln!(
+        report,
+        "Generated by `report` from a {profile} build. Times and allocations are from {runs}, \
+        answers are checked against `{}`.\n",
+        answers_path.display()
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "| Day | Part 1 | Part 2 | Parse | Part 1 time | Part 2 time | Total | Allocations | Status |"
+    )
+    .unwrap();
+    writeln!(
+        report,
+        "|-----|-------:|-------:|------:|------------:|------------:|------:|------------:|--------|"
+    )
+    .unwrap();
+
+    let mut passed = true;
+    for day in days {
+        eprintln!("Solving {}...", day.title);
+        let row = match runner::solve(day, config, timeout) {
+            Outcome::Finished(Solution {
+                answers,
+                phases,
+                allocations,
+            }) => {
+                let status = status(day.name, &answers, &accepted);
+                passed &= status.as_ref().is_none_or(|status| status == "pass");
+                [
+                    cell(&answers[0]),
+                    cell(&answers[1]),
+                    duration(phases.parse),
+                    duration(phases.part1),
+                    duration(phases.part2),
+                    duration(phases.total),
+                    allocations.to_string(),
+                    status.unwrap_or_else(|| "unchecked".to_owned()),
+                ]
+            }
+            outcome => {
+                passed = false;
+                let status = match outcome {
+                    Outcome::Failed(failure) => {
+                        eprintln!("{} failed: {failure}", day.name);
+                        "**failed**".to_owned()
+                    }
+                    _ => "**timed out**".to_owned(),
+                };
+                ["-", "-", "-", "-", "-", "-", "-", &status].map(str::to_owned)
+            }
+        };
+        writeln!(report, "| {} | {} |", day.title, row.join(" | ")).unwrap();
+    }
+
+    if let Err(err) = fs::write(output, report) {
+        eprintln!("could not write {}: {err}", output.display());
+        return false;
+    }
+    eprintln!("Wrote {}", output.display());
+    passed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_answers_of_variants() {
+        let accepted = toml::from_str("[day10]\npart1 = 13060\npart2 = \"FJUBULRZ\"").unwrap();
+        let answers = [Answer::Integer(13060), Answer::Text("FJUBULRZ".into())];
+        assert_eq!(
+            status("day10", &answers, &accepted).as_deref(),
+            Some("pass")
+        );
+        let answers = [Answer::Integer(13061), Answer::Text("FJUBULRZ".into())];
+        assert_eq!(
+            status("day10_other", &answers, &accepted).as_deref(),
+            Some("**wrong part1**")
+        );
+        assert_eq!(status("day11", &answers, &accepted), None);
+    }
+
+    #[test]
+    fn loads_the_answers_of_the_year() {
+        let dir = std::env::temp_dir().join(format!("aoc-report-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("answers.toml");
+        fs::write(
+            &path,
+            "2021 = 1
+[2022.day1]
+part1 = 1
+[2023.day1]
+part1 = 2",
+        )
+        .unwrap();
+        let answers = [load_answers(&path, 2022), load_answers(&path, 2024)];
+        let invalid = load_answers(&path, 2021);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let [answers, missing] = answers.map(Result::unwrap);
+        assert_eq!(answers.to_string(), "[day1]\npart1 = 1\n");
+        assert!(missing.is_empty());
+        assert!(invalid.unwrap_err().ends_with("`2021` is not a table"));
+    }
+}
@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use aoc_utils::{
+    runner::{self, Day, Outcome},
+    timing,
+};
+
+/// Answers requests from `server` until it is closed.
+///
+/// The available endpoints are:
+/// - `GET /days`: lists the names and titles of all days
+/// - `POST /day/{n}/part/{p}`: solves part `p` of day `n` (e.g. `7_alternative`) with the request
+///   body as the puzzle input. Both parts are solved, `elapsed_ns` is the time spent parsing and
+///   in part `p`, `total_ns` the time of the whole run. Days timed only as a whole, like plugins,
+///   spend all their time outside of the phases.
+pub fn run(server: &Server, days: &'static [Day], timeout: Option<Duration>) {
+    for mut request in server.incoming_requests() {
+        let (status, body) = handle(&mut request, days, timeout);
+        let response = Response::from_string(body.to_string())
+            .with_status_code(status)
+            .with_header(
+                "Content-Type: application/json"
+                    .parse::<Header>()
+                    .expect("header is valid"),
+            );
+        // the client may have disconnected already, there is nobody to report this to
+        let _ = request.respond(response);
+    }
+}
+
+fn error(status: u16, message: impl ToString) -> (u16, Value) {
+    (status, json!({ "error": message.to_string() }))
+}
+
+fn handle(request: &mut Request, days: &'static [Day], timeout: Option<Duration>) -> (u16, Value) {
+    let segments: Vec<_> = request.url().trim_matches('/').split('/').collect();
+    match (request.method(), &segments[..]) {
+        (Method::Get, ["days"]) => (
+            200,
+            days.iter()
+                .map(|day| json!({ "name": day.name, "title": day.title }))
+                .collect(),
+        ),
+        (Method::Post, ["day", day, "part", part]) => {
+            let name = format!("day{day}");
+            let Some(day) = days.iter().find(|day| day.name == name) else {
+                return error(404, format!("unknown day `{day}`"));
+            };
+            let part = match *part {
+                "1" => 1,
+                "2" => 2,
+                _ => return error(400, format!("unknown part `{part}`, expected `1` or `2`")),
+            };
+            let mut input = String::new();
+            if let Err(err) = request.as_reader().read_to_string(&mut input) {
+                return error(400, format!("could not read input: {err}"));
+            }
+            solve(day, part, input, timeout)
+        }
+        (_, ["days"] | ["day", _, "part", _]) => error(405, "method not allowed"),
+        _ => error(404, format!("unknown endpoint `{}`", request.url())),
+    }
+}
+
+fn solve(day: &Day, part: usize, input: String, timeout: Option<Duration>) -> (u16, Value) {
+    let solve = day.solve;
+    match runner::run_with_timeout(move || timing::measure(|| solve(&input)), timeout) {
+        Outcome::Finished((answers, phases)) => {
+            let part_time = match part {
+                1 => phases.part1,
+                _ => phases.part2,
+            };
+            (
+                200,
+                json!({
+                    "day": day.name,
+                    "part": part,
+                    "answer": answers[part - 1].to_json(),
+                    "elapsed_ns": (phases.parse + part_time).as_nanos() as u64,
+                    "total_ns": phases.total.as_nanos() as u64,
+                }),
+            )
+        }
+        Outcome::Failed(failure) => error(422, format!("solver failed: {failure}")),
+        Outcome::TimedOut(timeout) => error(504, format!("solver exceeded {timeout:?}")),
+    }
+}
+
+// the tests solve day 1 and look for a variant
+#[cfg(all(test, feature = "day1", feature = "day7_alternative"))]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+        thread,
+    };
+
+    use serde_json::Value;
+    use tiny_http::Server;
+
+    use aoc_2022::DAYS;
+
+    /// Starts a server on a free port and returns its address.
+    fn start() -> String {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let addr = server.server_addr().to_string();
+        thread::spawn(move || super::run(&server, DAYS, None));
+        addr
+    }
+
+    fn request(addr: &str, method: &str, path: &str, body: &str) -> (u16, Value) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+        (status, serde_json::from_str(body).unwrap())
+    }
+
+    const DAY1: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn solves_parts() {
+        let addr = start();
+        let (status, body) = request(&addr, "POST", "/day/1/part/1", DAY1);
+        assert_eq!(status, 200);
+        assert_eq!(body["answer"], 24000);
+        let total = body["total_ns"].as_u64().unwrap();
+        assert!(body["elapsed_ns"].as_u64().unwrap() <= total);
+        let (status, body) = request(&addr, "POST", "/day/1/part/2", DAY1);
+        assert_eq!(status, 200);
+        assert_eq!(body["answer"], 45000);
+    }
+
+    #[test]
+    fn lists_days() {
+        let (status, body) = request(&start(), "GET", "/days", "");
+        assert_eq!(status, 200);
+        assert!(body
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|day| day["name"] == "day7_alternative"));
+    }
+
+    #[test]
+    fn reports_errors() {
+        let addr = start();
+        assert_eq!(request(&addr, "POST", "/day/42/part/1", DAY1).0, 404);
+        assert_eq!(request(&addr, "POST", "/day/1/part/3", DAY1).0, 400);
+        assert_eq!(request(&addr, "GET", "/day/1/part/1", "").0, 405);
+        let (status, body) = request(&addr, "POST", "/day/1/part/1", "not a number");
+        assert_eq!(status, 422);
+        assert!(body["error"].as_str().unwrap().contains("src/day1.rs"));
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use serde_json::Value;
+
+use aoc_utils::{
+    answer::Answer,
+    config,
+    term::{self, Style},
+    timing::Phases,
+};
+
+use crate::Year;
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Where the day is built, so that the binary running `watch` is not replaced.
+const TARGET_DIR: &str = "target/watch";
+
+fn modified(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| fs::metadata(path).and_then(|meta| meta.modified()).ok())
+        .collect()
+}
+
+/// Builds only the day called `name`, with the same profile as this binary.
+///
+/// Returns the path of the binary, or `None` if the build failed. Cargo reports the errors itself.
+fn build(name: &str) -> Option<PathBuf> {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = manifest_dir.join(TARGET_DIR);
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    let mut cargo = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    cargo
+        .args([
+            "build",
+            "--quiet",
+            "--no-default-features",
+            "--features",
+            name,
+        ])
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir);
+    if profile == "release" {
+        cargo.arg("--release");
+    }
+    match cargo.status() {
+        Ok(status) if status.success() => Some(target_dir.join(profile).join(format!(
+            "{}{}",
+            env!("CARGO_PKG_NAME"),
+            env::consts::EXE_SUFFIX
+        ))),
+        Ok(_) => None,
+        Err(err) => {
+            eprintln!("could not run cargo: {err}");
+            None
+        }
+    }
+}
+
+/// Prints the result of one day as reported by `--json`, highlighting answers that changed since
+/// the `previous` run.
+fn report(result: &Value, previous: &mut HashMap<String, [Answer; 2]>, color: bool) {
+    let name = result["name"].as_str().unwrap_or_default();
+    println!("--- {} ---", result["title"].as_str().unwrap_or(name));
+    if let Some(error) = result["error"].as_str() {
+        println!("{}: {error}\n", Style::BOLD_RED.paint(color, "FAILED"));
+        return;
+    }
+    let answers: Option<Vec<_>> = result["answers"]
+        .as_array()
+        .and_then(|answers| answers.iter().map(Answer::from_json).collect());
+    let Some(answers) = answers.and_then(|answers| <[Answer; 2]>::try_from(answers).ok()) else {
+        println!(
+            "{}: malformed result {result}\n",
+            Style::BOLD_RED.paint(color, "FAILED")
+        );
+        return;
+    };
+    for (part, answer) in answers.iter().enumerate() {
+        let rendered = answer.render(color);
+        match previous.get(name).map(|previous| &previous[part]) {
+            Some(old) if old != answer => println!(
+                "Part {}: {} (was {old})",
+                part + 1,
+                Style::BOLD_YELLOW.paint(color, rendered)
+            ),
+            _ => println!("Part {}: {rendered}", part + 1),
+        }
+    }
+    let phases = Phases::from_json(&result["phases"]);
+    let timing = format!(
+        "parse {:.1?}, part 1 {:.1?}, part 2 {:.1?}, total {:.1?}",
+        phases.parse, phases.part1, phases.part2, phases.total
+    );
+    println!("{}\n", Style::GRAY.paint(color, timing));
+    previous.insert(name.to_owned(), answers);
+}
+
+/// Rebuilds and reruns the day called `name` of `year` whenever its source, its input or the
+/// configuration at `config_path` changes. Only returns if the day is unknown.
+pub fn main(year: &Year, name: &str, config_path: &Path, timeout: Option<Duration>) -> bool {
+    let name = match name.starts_with("day") {
+        true => name.to_owned(),
+        false => format!("day{name}"),
+    };
+    if !year.days.iter().any(|day| day.name == name) {
+        eprintln!("unknown day `{name}`");
+        return false;
+    }
+    // variants read the input of their day and are built from its code
+    let input_name = name.split('_').next().unwrap_or(&name);
+    let src_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join(year.dir)
+        .join("src");
+    let mut paths = vec![
+        src_dir.join(format!("{name}.rs")),
+        config::get().input_path(input_name),
+        config_path.to_owned(),
+    ];
+    if name != input_name {
+        paths.push(src_dir.join(format!("{input_name}.rs")));
+    }
+    let color = term::color();
+    let mut previous = HashMap::new();
+    let mut stamps = None;
+    loop {
+        let current = modified(&paths);
+        if stamps.as_ref() == Some(&current) {
+            thread::sleep(POLL_INTERVAL);
+            continue;
+        }
+        stamps = Some(current);
+
+        println!(
+            "{}",
+            Style::GRAY.paint(color, format!("Building {name}..."))
+        );
+        let Some(binary) = build(&name) else {
+            println!("{}\n", Style::BOLD_RED.paint(color, "BUILD FAILED"));
+            continue;
+        };
+        let mut run = Command::new(binary);
+        run.arg("--config")
+            .arg(config_path)
+            .args(["--year", &year.year.to_string()])
+            // the feature of a variant also builds its day, and plugins are not being watched
+            .args(["--day", &name, "--no-plugins"])
+            .arg("--json")
+            .stderr(Stdio::inherit());
+        if let Some(timeout) = timeout {
+            run.args(["--timeout", &timeout.as_secs_f64().to_string()]);
+        }
+        let output = match run.output() {
+            Ok(output) => output,
+            Err(err) => {
+                eprintln!("could not run {name}: {err}");
+                continue;
+            }
+        };
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            match serde_json::from_str(line) {
+                Ok(result) => report(&result, &mut previous, color),
+                Err(_) => println!("{line}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Runs day 10 from the dashboard and follows its events. This is a test of its own, as the
+//! dashboard makes all days render frames.
+#![cfg(feature = "day10")]
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    thread,
+};
+
+use aoc::dashboard;
+use aoc_2022::DAYS;
+use serde_json::Value;
+use tiny_http::Server;
+
+fn request(addr: &str, method: &str, path: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "{method} {path} HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\nContent-Length: 0\r\n\r\n"
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    (
+        head.split(' ').nth(1).unwrap().parse().unwrap(),
+        body.to_owned(),
+    )
+}
+
+#[test]
+fn streams_frames_of_a_run() {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_string();
+    thread::spawn(move || dashboard::run(&server, DAYS, None));
+
+    let (status, page) = request(&addr, "GET", "/");
+    assert_eq!(status, 200);
+    assert!(page.contains("new EventSource(\"/events\")"));
+    assert_eq!(request(&addr, "POST", "/run/1").0, 404);
+
+    let mut events = TcpStream::connect(&addr).unwrap();
+    write!(events, "GET /events HTTP/1.1\r\nHost: {addr}\r\n\r\n").unwrap();
+    let mut events = BufReader::new(events);
+    // the stream is registered once its head was sent
+    let mut line = String::new();
+    while line != "\r\n" {
+        line.clear();
+        events.read_line(&mut line).unwrap();
+    }
+
+    assert_eq!(request(&addr, "POST", "/run/10").0, 202);
+    assert_eq!(request(&addr, "POST", "/run/10").0, 409);
+
+    let mut event = String::new();
+    let mut frames = 0;
+    let finish = loop {
+        line.clear();
+        events.read_line(&mut line).unwrap();
+        if let Some(name) = line.strip_prefix("event: ") {
+            event = name.trim().to_owned();
+        } else if let Some(data) = line.strip_prefix("data: ") {
+            let data: Value = serde_json::from_str(data).unwrap();
+            match &event[..] {
+                "frame" => {
+                    assert_eq!(data["rows"].as_array().unwrap().len(), 6);
+                    frames += 1;
+                }
+                "finish" => break data,
+                _ => {}
+            }
+        }
+    };
+    assert!(frames > 0);
+    assert_eq!(finish["error"], Value::Null);
+    assert_eq!(finish["answers"][1]["text"], "FJUBULRZ");
+}
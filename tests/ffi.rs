@@ -0,0 +1,43 @@
+//! Checks `include/aoc.h`, then builds `tests/ffi/harness.c` against the `cdylib` and runs it.
+#![cfg(all(unix, feature = "day1"))]
+
+use std::{env, fs, path::Path, process::Command};
+
+#[test]
+fn header_is_up_to_date() {
+    let generated = fs::read_to_string(concat!(env!("OUT_DIR"), "/aoc.h")).unwrap();
+    assert!(
+        generated == include_str!("../include/aoc.h"),
+        "`include/aoc.h` is outdated, build with `AOC_UPDATE_HEADER=1` to update it"
+    );
+}
+
+#[test]
+fn c_harness() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // the test runs from `target/<profile>/deps`, the library is built next to it
+    let exe = env::current_exe().unwrap();
+    let lib_dir = exe.parent().unwrap().parent().unwrap();
+    let harness = lib_dir.join("ffi-harness");
+
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_owned());
+    let status = Command::new(&cc)
+        .arg(manifest_dir.join("tests/ffi/harness.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .args(["-laoc", "-Wall", "-Werror", "-o"])
+        .arg(&harness)
+        .status()
+        .unwrap_or_else(|err| panic!("could not run `{cc}`: {err}"));
+    assert!(status.success(), "could not compile the harness");
+
+    let output = Command::new(&harness).output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
@@ -0,0 +1,59 @@
+// Calls the solvers through `include/aoc.h`, run by `tests/ffi.rs`.
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static const char INPUT[] = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+static int failures = 0;
+
+static void check(const char *name, AocStatus status, AocStatus expected_status,
+                  const AocBuffer *out, const char *expected_text) {
+    if (status != expected_status) {
+        printf("%s: expected status %d, got %d\n", name, expected_status, status);
+        failures++;
+    } else if (expected_text != NULL && strcmp(out->ptr, expected_text) != 0) {
+        printf("%s: expected `%s`, got `%s`\n", name, expected_text, out->ptr);
+        failures++;
+    }
+}
+
+int main(void) {
+    char bytes[16];
+    AocBuffer out = {bytes, sizeof bytes, 0};
+    const uint8_t *input = (const uint8_t *)INPUT;
+    size_t len = strlen(INPUT);
+
+    check("part 1", aoc_solve(2022, "1", 1, input, len, &out), AOC_STATUS_OK, &out, "24000");
+    check("part 2", aoc_solve(2022, "day1", 2, input, len, &out), AOC_STATUS_OK, &out, "45000");
+    check("unknown day", aoc_solve(2022, "42", 1, input, len, &out), AOC_STATUS_UNKNOWN_DAY, &out,
+          NULL);
+    check("unknown year", aoc_solve(2015, "1", 1, input, len, &out), AOC_STATUS_UNKNOWN_DAY, &out,
+          NULL);
+    check("invalid part", aoc_solve(2022, "1", 3, input, len, &out), AOC_STATUS_INVALID_PART, &out,
+          NULL);
+    check("null pointer", aoc_solve(2022, NULL, 1, input, len, &out), AOC_STATUS_NULL_POINTER,
+          &out, NULL);
+
+    AocBuffer small = {bytes, 3, 0};
+    check("small buffer", aoc_solve(2022, "1", 1, input, len, &small), AOC_STATUS_BUFFER_TOO_SMALL,
+          &small, NULL);
+    if (small.len != 5) {
+        printf("small buffer: expected a needed length of 5, got %zu\n", small.len);
+        failures++;
+    }
+
+    char message[256];
+    AocBuffer failure = {message, sizeof message, 0};
+    const uint8_t *invalid = (const uint8_t *)"x";
+    check("panic", aoc_solve(2022, "1", 1, invalid, 1, &failure), AOC_STATUS_SOLVER_FAILED,
+          &failure, NULL);
+    if (strstr(message, "src/day1.rs") == NULL) {
+        printf("panic: expected the location in `%s`\n", message);
+        failures++;
+    }
+
+    return failures == 0 ? 0 : 1;
+}
@@ -0,0 +1,47 @@
+//! Builds `examples/plugin.rs` and loads it like the runner does.
+#![cfg(unix)]
+
+use std::{env, fs, path::Path, process::Command};
+
+use aoc::plugin;
+use aoc_utils::runner;
+
+#[test]
+fn loads_example_plugin() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--quiet", "--example", "plugin", "--manifest-path"])
+        .arg(manifest_dir.join("Cargo.toml"))
+        .status()
+        .unwrap();
+    assert!(status.success(), "could not build the plugin");
+
+    // the test runs from `target/debug/deps`, the examples are built next to it
+    let exe = env::current_exe().unwrap();
+    let examples = exe.parent().unwrap().parent().unwrap().join("examples");
+    let file_name = format!(
+        "{}plugin{}",
+        env::consts::DLL_PREFIX,
+        env::consts::DLL_SUFFIX
+    );
+    let dir = env::temp_dir().join(format!("aoc-plugins-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::copy(examples.join(&file_name), dir.join(&file_name)).unwrap();
+    fs::write(dir.join("README.txt"), "not a plugin").unwrap();
+
+    let days = plugin::load_dir(&dir, &[]);
+    let [day] = &days[..] else {
+        panic!("expected one day, got {}", days.len());
+    };
+    assert_eq!(day.name, "day0");
+    assert_eq!(day.title, "Day 0: Plugin Example");
+    let answers = (day.solve)(day.input).map(|answer| answer.to_string());
+    assert_eq!(answers, ["6", "16"]);
+
+    let failure = runner::isolated(|| (day.solve)("1 x")).err().unwrap();
+    assert!(failure.to_string().contains("invalid number"), "{failure}");
+
+    // a plugin may not replace a day that is already known
+    assert!(plugin::load_dir(&dir, &days).is_empty());
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,165 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde_json::{json, Value};
+
+use crate::{
+    grid::Grid,
+    point::Point,
+    term::{self, Style},
+};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// Letters drawn on a screen, like the CRT of day 10
+    Image(Image),
+}
+
+impl Answer {
+    /// Renders the answer for the terminal, drawing images on their own lines.
+    pub fn render(&self, color: bool) -> String {
+        match self {
+            Answer::Image(image) => format!("\n{}", image.render(color)),
+            _ => self.to_string(),
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        match self {
+            Answer::Integer(integer) => json!(integer),
+            Answer::Text(text) => json!(text),
+            Answer::Image(image) => json!({
+                "width": image.pixels.width(),
+                "height": image.pixels.height(),
+                "rows": image.render(false).lines().collect::<Vec<_>>(),
+                "text": image.ocr(),
+            }),
+        }
+    }
+
+    /// Reads an answer written by [`Answer::to_json`].
+    pub fn from_json(value: &Value) -> Option<Answer> {
+        match value {
+            Value::Number(number) => number.as_i64().map(Answer::Integer),
+            Value::String(text) => Some(Answer::Text(text.clone())),
+            Value::Object(image) => {
+                let rows = image.get("rows")?.as_array()?;
+                let rows: Option<Vec<_>> = rows.iter().map(Value::as_str).collect();
+                let pixels = Grid::parse(&rows?.join("\n"), |_, pixel| pixel == '#');
+                Some(Answer::Image(pixels.into()))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Shows the letters of images if they can be read, otherwise their pixels.
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(integer) => write!(f, "{integer}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Image(image) => match image.ocr() {
+                Some(text) => write!(f, "{text}"),
+                None => write!(f, "\n{}", image.render(false)),
+            },
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($int:ty),*) => {
+        $(impl From<$int> for Answer {
+            fn from(integer: $int) -> Self {
+                Answer::Integer(integer.try_into().expect("answer does not fit into an i64"))
+            }
+        })*
+    };
+}
+
+impl_from_integer!(i32, i64, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<Image> for Answer {
+    fn from(image: Image) -> Self {
+        Answer::Image(image)
+    }
+}
+
+//////////////////////////////////
+
+/// A bitmap of lit and dark pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<bool>,
+}
+
+/// The letters known to [`Image::ocr`], each 4 pixels wide and followed by a blank column.
+const LETTERS: &str = "ABCEFGHIJKLOPRSUZ";
+const FONT: [&str; 6] = [
+    ".##. ###. .##. #### #### .##. #..# .### ..## #..# #... .##. ###. ###. .### #..# ####",
+    "#..# #..# #..# #... #... #..# #..# ..#. ...# #.#. #... #..# #..# #..# #... #..# ...#",
+    "#..# ###. #... ###. ###. #... #### ..#. ...# ##.. #... #..# #..# #..# #... #..# ..#.",
+    "#### #..# #... #... #... #.## #..# ..#. ...# #.#. #... #..# ###. ###. .##. #..# .#..",
+    "#..# #..# #..# #... #... #..# #..# ..#. #..# #.#. #... #..# #... #.#. ...# #..# #...",
+    "#..# ###. .##. #### #... .### #..# .### .##. #..# #### .##. #... #..# ###. .##. ####",
+];
+const LETTER_WIDTH: usize = 5;
+
+impl Image {
+    /// Renders one line per row. Lit pixels are green with `color` and `#` without.
+    pub fn render(&self, color: bool) -> String {
+        let mut out = String::new();
+        for row in self.pixels.rows() {
+            for lit in row {
+                out += &match (lit, color) {
+                    (true, _) => term::glyph(color, Style::BG_GREEN, " ", "#"),
+                    (false, true) => " ".to_owned(),
+                    (false, false) => ".".to_owned(),
+                };
+            }
+            out += "\n";
+        }
+        out
+    }
+
+    /// Reads the letters drawn in the image, if it only consists of letters known to [`FONT`].
+    pub fn ocr(&self) -> Option<String> {
+        if self.pixels.height() != FONT.len() || !self.pixels.width().is_multiple_of(LETTER_WIDTH) {
+            return None;
+        }
+        (0..self.pixels.width() / LETTER_WIDTH)
+            .map(|idx| {
+                LETTERS
+                    .chars()
+                    .enumerate()
+                    .find_map(|(letter_idx, letter)| {
+                        let matches = FONT.iter().enumerate().all(|(y, font_row)| {
+                            (0..LETTER_WIDTH).all(|x| {
+                                let lit = self.pixels
+                                    [Point::new((idx * LETTER_WIDTH + x) as i64, y as i64)];
+                                // the last letter has no blank column after it
+                                let font_lit =
+                                    font_row.as_bytes().get(letter_idx * LETTER_WIDTH + x);
+                                lit == (font_lit == Some(&b'#'))
+                            })
+                        });
+                        matches.then_some(letter)
+                    })
+            })
+            .collect()
+    }
+}
+
+impl From<Grid<bool>> for Image {
+    fn from(pixels: Grid<bool>) -> Self {
+        Self { pixels }
+    }
+}
@@ -0,0 +1,140 @@
+use std::{
+    env, fs,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use serde_json::{json, Value};
+
+use crate::{answer::Answer, config::Config, runner::Day};
+
+/// Identifies a build by the hash of the file at `path`. `None` if it cannot be read.
+pub fn file_id(path: &Path) -> Option<u64> {
+    let binary = fs::read(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    binary.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+/// Identifies the running binary, so that answers found by other builds are never used.
+fn build_id() -> Option<u64> {
+    static BUILD_ID: OnceLock<Option<u64>> = OnceLock::new();
+    *BUILD_ID.get_or_init(|| file_id(&env::current_exe().ok()?))
+}
+
+/// Identifies what the answers of `day` depend on besides its code: `input` and the parameters of
+/// the day and of its base. Stable between builds with the same toolchain, so the build script can
+/// key the answers it precomputes with it.
+pub fn answer_key(day: &Day, input: &str, config: &Config) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    input.hash(&mut hasher);
+    // variants read the parameters of their day
+    let base = day.name.split('_').next().unwrap_or(day.name);
+    for name in [day.name, base] {
+        config
+            .day_table(name)
+            .map(ToString::to_string)
+            .hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// The answers of earlier runs, stored as one JSON file per day, input and build.
+///
+/// Both parts are stored together, as the days always solve both.
+pub struct Cache {
+    dir: PathBuf,
+    build_id: Option<u64>,
+}
+
+impl Cache {
+    pub fn new(dir: &Path) -> Cache {
+        Cache {
+            dir: dir.to_owned(),
+            build_id: build_id(),
+        }
+    }
+
+    /// The file of the answers of `day` for `input`, which also depend on the parameters of the
+    /// day and on the build, including that of the day if it is not built into the runner. `None`
+    /// if the build is unknown.
+    fn path(&self, day: &Day, input: &str, config: &Config) -> Option<PathBuf> {
+        let mut hasher = DefaultHasher::new();
+        self.build_id?.hash(&mut hasher);
+        day.build_id.hash(&mut hasher);
+        answer_key(day, input, config).hash(&mut hasher);
+        Some(
+            self.dir
+                .join(format!("{}-{:016x}.json", day.name, hasher.finish())),
+        )
+    }
+
+    /// Returns the answers stored for `day` and `input`, if there are any.
+    pub fn get(&self, day: &Day, input: &str, config: &Config) -> Option<[Answer; 2]> {
+        let text = fs::read_to_string(self.path(day, input, config)?).ok()?;
+        let entry: Value = serde_json::from_str(&text).ok()?;
+        Some([
+            Answer::from_json(&entry["part1"])?,
+            Answer::from_json(&entry["part2"])?,
+        ])
+    }
+
+    /// Stores the `answers` of `day` for `input`.
+    pub fn insert(
+        &self,
+        day: &Day,
+        input: &str,
+        config: &Config,
+        answers: &[Answer; 2],
+    ) -> Result<(), String> {
+        let Some(path) = self.path(day, input, config) else {
+            return Ok(());
+        };
+        let entry = json!({
+            "part1": answers[0].to_json(),
+            "part2": answers[1].to_json(),
+        });
+        fs::create_dir_all(&self.dir)
+            .and_then(|()| fs::write(&path, entry.to_string()))
+            .map_err(|err| format!("{}: {err}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(name: &'static str) -> Day {
+        Day {
+            name,
+            title: name,
+            input: "",
+            solve: &|_| unreachable!(),
+            build_id: None,
+        }
+    }
+
+    #[test]
+    fn keys_by_day_input_and_params() {
+        let dir = env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        let config: Config = toml::from_str("[days.day7]\ndisk_size = 100").unwrap();
+        let answers = [Answer::Integer(7), Answer::Text("seven".to_owned())];
+        cache
+            .insert(&day("day7"), "input", &config, &answers)
+            .unwrap();
+
+        assert_eq!(cache.get(&day("day7"), "input", &config), Some(answers));
+        assert_eq!(cache.get(&day("day7"), "other input", &config), None);
+        assert_eq!(cache.get(&day("day7_alternative"), "input", &config), None);
+        let rebuilt = Day {
+            build_id: Some(1),
+            ..day("day7")
+        };
+        assert_eq!(cache.get(&rebuilt, "input", &config), None);
+        let changed: Config = toml::from_str("[days.day7]\ndisk_size = 200").unwrap();
+        assert_eq!(cache.get(&day("day7"), "input", &changed), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
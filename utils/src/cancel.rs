@@ -0,0 +1,44 @@
+use std::{
+    cell::RefCell,
+    panic,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// A flag shared between the runner and a running day, telling the day to stop.
+#[derive(Clone, Default)]
+pub struct Token(Arc<AtomicBool>);
+
+impl Token {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// The payload of the unwind started by [`checkpoint`].
+pub struct Cancelled;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Makes `token` the one checked by [`checkpoint`] on this thread.
+pub fn set_current(token: Token) {
+    CURRENT.with(|current| *current.borrow_mut() = Some(token));
+}
+
+/// Stops the current day if it was cancelled.
+///
+/// Long running loops should call this once per iteration. It unwinds with a [`Cancelled`]
+/// payload, which does not trigger the panic hook.
+pub fn checkpoint() {
+    if CURRENT.with(|current| current.borrow().as_ref().is_some_and(Token::is_cancelled)) {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    borrow::Cow,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::Duration,
+};
+
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::{runner::Day, term::ColorMode};
+
+/// The default location of the configuration, relative to the working directory.
+pub const PATH: &str = "aoc.toml";
+
+/// Defaults for the runner and parameters of the days, read from [`PATH`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Read the inputs from this directory instead of the ones embedded at compile time
+    pub input_dir: Option<PathBuf>,
+    /// The inputs of the selected year, read from disk if only a profile is configured
+    #[serde(skip)]
+    pub year_input_dir: PathBuf,
+    /// Read the inputs from this subdirectory of the input directory, e.g. `examples`
+    pub profile: Option<String>,
+    /// Whether to color the output, overridden by `--color`
+    pub color: ColorMode,
+    /// The frames per second of the debug visualizations, `0` for no limit
+    pub fps: u32,
+    /// How often each day is solved, reporting the mean time
+    pub repeat: u32,
+    /// Load additional days of each year from the shared libraries in its subdirectory, e.g.
+    /// `plugins/2022`, see `aoc::plugin`
+    pub plugin_dir: PathBuf,
+    /// Store the answers in a subdirectory of this one per year and reuse them while the input and
+    /// the binary are the same, unless `--no-cache` is given
+    pub cache_dir: PathBuf,
+    /// The parameters of each day, see [`Config::params`]
+    days: toml::Table,
+    /// The file the configuration was loaded from, named in errors
+    #[serde(skip)]
+    path: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            input_dir: None,
+            year_input_dir: PathBuf::from("inputs"),
+            profile: None,
+            color: ColorMode::Auto,
+            fps: 200,
+            repeat: 1,
+            plugin_dir: PathBuf::from("plugins"),
+            cache_dir: PathBuf::from(".aoc-cache"),
+            days: toml::Table::new(),
+            path: PathBuf::from(PATH),
+        }
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Reads the configuration at `path`, falling back to the defaults if there is none.
+pub fn load(path: &Path) -> Result<Config, String> {
+    let config = match fs::read_to_string(path) {
+        Ok(text) => toml::from_str(&text).map_err(|err| format!("{}: {err}", path.display()))?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Config::default(),
+        Err(err) => return Err(format!("{}: {err}", path.display())),
+    };
+    Ok(Config {
+        path: path.to_owned(),
+        ..config
+    })
+}
+
+/// Makes `config` available through [`get`]. Only the first call has an effect.
+pub fn init(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+/// Returns the configuration passed to [`init`], or the defaults if there was none.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+impl Config {
+    /// The file the input of the day called `name` is read from.
+    pub fn input_path(&self, name: &str) -> PathBuf {
+        let mut path = self
+            .input_dir
+            .clone()
+            .unwrap_or_else(|| self.year_input_dir.clone());
+        if let Some(profile) = &self.profile {
+            path.push(profile);
+        }
+        path.join(format!("{name}.txt"))
+    }
+
+    /// Returns the input of `day`, which is only read from disk if an input directory or profile
+    /// is configured.
+    pub fn input(&self, day: &Day) -> Result<Cow<'static, str>, String> {
+        if self.input_dir.is_none() && self.profile.is_none() {
+            return Ok(day.input.into());
+        }
+        let path = self.input_path(day.name);
+        fs::read_to_string(&path)
+            .map(Cow::Owned)
+            .map_err(|err| format!("could not read {}: {err}", path.display()))
+    }
+
+    /// How long to show each frame of a debug visualization.
+    pub fn frame_delay(&self) -> Duration {
+        match self.fps {
+            0 => Duration::ZERO,
+            fps => Duration::from_secs(1) / fps,
+        }
+    }
+
+    /// Returns the `[days.<name>]` table of the day called `name`, if there is one.
+    pub fn day_table(&self, name: &str) -> Option<&toml::Value> {
+        self.days.get(name)
+    }
+
+    /// Returns the parameters of the day called `name` from its `[days.<name>]` table.
+    ///
+    /// Missing parameters keep their default values. Panics if the table does not match `T`.
+    pub fn params<T: DeserializeOwned + Default>(&self, name: &str) -> T {
+        match self.days.get(name) {
+            Some(table) => table.clone().try_into().unwrap_or_else(|err| {
+                panic!("invalid `[days.{name}]` in {}: {err}", self.path.display())
+            }),
+            None => T::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Params {
+        row: i64,
+        cycles: Vec<usize>,
+    }
+
+    impl Default for Params {
+        fn default() -> Self {
+            Self {
+                row: 10,
+                cycles: vec![1, 2],
+            }
+        }
+    }
+
+    #[test]
+    fn day_params_fall_back_to_defaults() {
+        let mut config: Config = toml::from_str(
+            r#"
+            fps = 0
+            profile = "examples"
+
+            [days.day1]
+            row = 3
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.frame_delay(), Duration::ZERO);
+        assert_eq!(config.color, ColorMode::Auto);
+        assert_eq!(
+            config.input_path("day1"),
+            Path::new("inputs/examples/day1.txt")
+        );
+        config.year_input_dir = PathBuf::from("2022/inputs");
+        assert_eq!(
+            config.input_path("day1"),
+            Path::new("2022/inputs/examples/day1.txt")
+        );
+        assert_eq!(
+            config.params::<Params>("day1"),
+            Params {
+                row: 3,
+                cycles: vec![1, 2]
+            }
+        );
+        assert_eq!(config.params::<Params>("day2"), Params::default());
+    }
+
+    #[test]
+    #[should_panic = "invalid `[days.day1]` in aoc.toml"]
+    fn unknown_params_panic() {
+        let config: Config = toml::from_str("[days.day1]\nrwo = 3").unwrap();
+        config.params::<Params>("day1");
+    }
+
+    #[test]
+    fn params_panic_names_the_loaded_file() {
+        let dir = std::env::temp_dir().join(format!("aoc-config-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("other.toml");
+        fs::write(&path, "[days.day1]\nrwo = 3").unwrap();
+        let config = load(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        let payload = std::panic::catch_unwind(|| config.params::<Params>("day1")).unwrap_err();
+        let message = payload.downcast_ref::<String>().unwrap();
+        let expected = format!("invalid `[days.day1]` in {}: ", path.display());
+        assert!(message.starts_with(&expected), "{message}");
+    }
+}
@@ -0,0 +1,70 @@
+use std::{
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc::{self, Receiver, SyncSender},
+        Mutex,
+    },
+};
+
+use serde_json::{json, Map, Value};
+
+/// How many frames may wait for the dashboard before the days are slowed down to its pace.
+const BUFFERED_FRAMES: usize = 16;
+
+/// One frame of a visualization, drawn with the glyphs of its plain terminal view.
+pub struct Frame {
+    pub rows: Vec<String>,
+    /// The CSS color of each glyph, glyphs without one are left empty
+    pub legend: &'static [(char, &'static str)],
+}
+
+impl Frame {
+    pub fn to_json(&self) -> Value {
+        let legend: Map<_, _> = self
+            .legend
+            .iter()
+            .map(|(glyph, color)| (glyph.to_string(), json!(color)))
+            .collect();
+        json!({ "rows": self.rows, "legend": legend })
+    }
+}
+
+static WATCHING: AtomicBool = AtomicBool::new(false);
+static SINK: Mutex<Option<SyncSender<Frame>>> = Mutex::new(None);
+
+/// Whether the dashboard is watching, i.e. whether the days should render frames at all.
+pub fn watching() -> bool {
+    WATCHING.load(Ordering::Relaxed)
+}
+
+/// Passes the frames of all days to the returned receiver from now on.
+pub fn watch() -> Receiver<Frame> {
+    let (sender, receiver) = mpsc::sync_channel(BUFFERED_FRAMES);
+    *SINK.lock().unwrap() = Some(sender);
+    WATCHING.store(true, Ordering::Relaxed);
+    receiver
+}
+
+/// Renders a frame with `render` and sends it to the dashboard, if it is watching.
+///
+/// `render` is one of the functions drawing the debug views, called without colors.
+pub fn send(
+    legend: &'static [(char, &'static str)],
+    render: impl FnOnce(&mut Vec<u8>) -> io::Result<()>,
+) {
+    if !watching() {
+        return;
+    }
+    let mut out = Vec::new();
+    render(&mut out).expect("writing to a Vec does not fail");
+    let rows = String::from_utf8_lossy(&out)
+        .lines()
+        .map(str::to_owned)
+        .collect();
+    let sink = SINK.lock().unwrap().clone();
+    if let Some(sink) = sink {
+        // the dashboard only stops watching when the program exits
+        let _ = sink.send(Frame { rows, legend });
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    iter,
+    ops::{Index, IndexMut},
+};
+
+use strum::IntoEnumIterator;
+
+use crate::point::{Direction, Point};
+
+/// A rectangular grid of cells stored row by row in one `Vec`.
+///
+/// Cells are addressed by [`Point`]s, with `(0, 0)` being the top left cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a `width` by `height` grid filled with `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parses a character map with one row per line, converting each character with `cell`.
+    ///
+    /// Panics if the lines are not all of the same length.
+    pub fn parse(input: &str, mut cell: impl FnMut(Point, char) -> T) -> Self {
+        let mut width = None;
+        let mut cells = vec![];
+        for (y, line) in input.lines().enumerate() {
+            let len = cells.len();
+            cells.extend(
+                line.chars()
+                    .enumerate()
+                    .map(|(x, char)| cell(Point::new(x as i64, y as i64), char)),
+            );
+            let line_width = cells.len() - len;
+            assert_eq!(
+                *width.get_or_insert(line_width),
+                line_width,
+                "line {} has a different length",
+                y + 1
+            );
+        }
+        let width = width.unwrap_or(0);
+        Self {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        (0..self.width as i64).contains(&pos.x) && (0..self.height as i64).contains(&pos.y)
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.contains(pos).then(|| &self[pos])
+    }
+
+    /// Returns the index of `pos` in `cells`, panicking if it is out of bounds.
+    fn index_of(&self, pos: Point) -> usize {
+        assert!(self.contains(pos), "position {pos:?} is out of bounds");
+        pos.y as usize * self.width + pos.x as usize
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(
+        &self,
+        x: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone {
+        assert!(x < self.width, "column {x} is out of bounds");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over all cells together with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(idx, cell)| (Point::new((idx % width) as i64, (idx / width) as i64), cell))
+    }
+
+    /// Iterates over the positions in each [`Direction`] from `pos` that are inside the grid.
+    pub fn neighbors(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::iter()
+            .map(move |direction| pos + direction)
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    /// Adds `left`, `top`, `right` and `bottom` columns or rows of `value` around the grid.
+    ///
+    /// Existing cells move by `(left, top)`. Growing by nothing keeps the cells where they are.
+    pub fn grow(&mut self, [left, top, right, bottom]: [usize; 4], value: T)
+    where
+        T: Clone,
+    {
+        if [left, top, right, bottom] == [0; 4] {
+            return;
+        }
+        let width = left + self.width + right;
+        let height = top + self.height + bottom;
+        let mut cells = Vec::with_capacity(width * height);
+        cells.extend(iter::repeat_n(value.clone(), top * width));
+        let mut old_cells = self.cells.drain(..);
+        for _ in 0..self.height {
+            cells.extend(iter::repeat_n(value.clone(), left));
+            cells.extend(old_cells.by_ref().take(self.width));
+            cells.extend(iter::repeat_n(value.clone(), right));
+        }
+        drop(old_cells);
+        cells.extend(iter::repeat_n(value, bottom * width));
+        *self = Self {
+            width,
+            height,
+            cells,
+        };
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point) -> &Self::Output {
+        &self.cells[self.index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pos: Point) -> &mut Self::Output {
+        let idx = self.index_of(pos);
+        &mut self.cells[idx]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |_, char| char.to_digit(10).unwrap())
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(1).rev().copied().collect::<Vec<_>>(), [5, 2]);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    #[should_panic = "line 2 has a different length"]
+    fn parse_uneven() {
+        digits("123\n45\n");
+    }
+
+    #[test]
+    fn neighbors_stay_inside() {
+        let grid = digits("123\n456\n");
+        assert_eq!(
+            grid.neighbors(Point::new(0, 0)).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(
+            grid.neighbors(Point::new(1, 1)).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(2, 1), Point::new(0, 1)]
+        );
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(-1, 0)), None);
+    }
+
+    #[test]
+    fn grow_in_all_directions() {
+        let mut grid = digits("12\n34\n");
+        grid.grow([1, 2, 0, 1], 0);
+        assert_eq!(grid.to_string(), "000\n000\n012\n034\n000\n");
+        assert_eq!(
+            grid.iter().find(|(_, cell)| **cell == 4).unwrap().0,
+            Point::new(2, 3)
+        );
+    }
+}
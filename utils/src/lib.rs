@@ -0,0 +1,16 @@
+//! The helpers shared by the days of all years, and the runner solving them.
+
+pub mod allocations;
+pub mod answer;
+pub mod cache;
+pub mod cancel;
+pub mod config;
+pub mod frames;
+pub mod grid;
+pub mod parse;
+pub mod point;
+pub mod range_set;
+pub mod runner;
+pub mod search;
+pub mod term;
+pub mod timing;
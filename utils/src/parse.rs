@@ -0,0 +1,182 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending, multispace0},
+    combinator::{all_consuming, map, map_res, opt, recognize},
+    error::{VerboseError, VerboseErrorKind},
+    sequence::{pair, preceded, separated_pair, terminated},
+    Finish,
+};
+
+use crate::point::Point;
+
+/// The result of a parser, collecting the contexts it failed in.
+pub type IResult<'a, T> = nom::IResult<&'a str, T, VerboseError<&'a str>>;
+
+/// Parses a non-negative decimal number, e.g. `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<'_, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// Parses a decimal number with an optional minus sign, e.g. `-42`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<'_, T> {
+    map_res(recognize(pair(opt(char('-')), digit1)), str::parse)(input)
+}
+
+/// Parses a point written as `x,y`.
+pub fn point(input: &str) -> IResult<'_, Point> {
+    map(separated_pair(signed, char(','), signed), |(x, y)| {
+        Point::new(x, y)
+    })(input)
+}
+
+/// Parses a point written as `x=.., y=..`.
+pub fn labeled_point(input: &str) -> IResult<'_, Point> {
+    map(
+        separated_pair(
+            preceded(tag("x="), signed),
+            tag(", "),
+            preceded(tag("y="), signed),
+        ),
+        |(x, y)| Point::new(x, y),
+    )(input)
+}
+
+/// Parses one item per line with `item`, stopping at the end of the input or a blank line.
+pub fn lines<'a, T>(
+    item: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated(line_ending, item)
+}
+
+/// Parses blocks separated by blank lines with `block`.
+pub fn blocks<'a, T>(
+    block: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated(pair(line_ending, line_ending), block)
+}
+
+/// Like [`nom::multi::separated_list1`], but an item that fails after a separator is an error
+/// instead of the end of the list, unless nothing but line breaks follow.
+///
+/// This way errors point into the broken item rather than to where the list stopped.
+fn separated<'a, S, T>(
+    mut separator: impl FnMut(&'a str) -> IResult<'a, S>,
+    mut item: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    move |input| {
+        let (mut input, first) = item(input)?;
+        let mut items = vec![first];
+        while let Ok((rest, _)) = separator(input) {
+            if rest.is_empty() || rest.starts_with(['\n', '\r']) {
+                break;
+            }
+            let (rest, next) = item(rest)?;
+            items.push(next);
+            input = rest;
+        }
+        Ok((input, items))
+    }
+}
+
+/// Runs `parser` on the whole `input`, allowing only trailing whitespace to remain.
+pub fn finish<'a, T>(
+    input: &'a str,
+    parser: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> Result<T, Error> {
+    all_consuming(terminated(parser, multispace0))(input)
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|err| Error::new(input, err))
+}
+
+//////////////////////////////////
+
+/// A parse error, pointing to where in the input it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    /// The line of the error, starting at 1
+    pub line: usize,
+    /// The column of the error in characters, starting at 1
+    pub column: usize,
+    pub message: String,
+}
+
+impl Error {
+    fn new(input: &str, err: VerboseError<&str>) -> Self {
+        // the first error is the innermost one, the contexts follow from the inside out
+        let (rest, kind) = &err.errors[0];
+        let consumed = &input[..input.len() - rest.len()];
+        let line_start = consumed.rfind('\n').map_or(0, |idx| idx + 1);
+        let mut message = match kind {
+            VerboseErrorKind::Char(char) => format!("expected `{char}`"),
+            VerboseErrorKind::Context(context) => format!("invalid {context}"),
+            VerboseErrorKind::Nom(kind) => {
+                format!("expected {}", kind.description().to_lowercase())
+            }
+        };
+        message += &match rest.lines().next() {
+            Some(found) if found.chars().count() > 16 => {
+                format!(
+                    ", found `{}...`",
+                    found.chars().take(16).collect::<String>()
+                )
+            }
+            Some(found) if !found.is_empty() => format!(", found `{found}`"),
+            _ => ", found end of line".to_owned(),
+        };
+        let contexts = err.errors[1..].iter().filter_map(|(_, kind)| match kind {
+            VerboseErrorKind::Context(context) => Some(*context),
+            _ => None,
+        });
+        for context in contexts {
+            message += &format!(" in {context}");
+        }
+        Self {
+            line: consumed.matches('\n').count() + 1,
+            column: consumed[line_start..].chars().count() + 1,
+            message,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use nom::error::context;
+
+    use super::*;
+
+    #[test]
+    fn numbers_and_points() {
+        assert_eq!(finish("-12", signed::<i8>), Ok(-12));
+        assert!(finish("-12", unsigned::<u8>).is_err());
+        assert!(finish("300", unsigned::<u8>).is_err());
+        assert_eq!(
+            finish("1,-2\n3,4\n", lines(point)),
+            Ok(vec![Point::new(1, -2), Point::new(3, 4)])
+        );
+        assert_eq!(finish("x=5, y=-7", labeled_point), Ok(Point::new(5, -7)));
+    }
+
+    #[test]
+    fn errors_point_to_line_and_column() {
+        let err = finish("1,2\n3;4\n", lines(context("point", point))).unwrap_err();
+        assert_eq!(err.to_string(), "2:2: expected `,`, found `;4` in point");
+        let err = finish("1\n2\n\n3\nx", blocks(lines(unsigned::<u32>))).unwrap_err();
+        assert_eq!(err.to_string(), "5:1: expected digit, found `x`");
+        let err = finish("1\n2\n\n\n3", blocks(lines(unsigned::<u32>))).unwrap_err();
+        assert_eq!(err.to_string(), "5:1: expected end of file, found `3`");
+    }
+}
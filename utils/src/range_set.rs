@@ -0,0 +1,188 @@
+use std::ops::RangeInclusive;
+
+/// A set of integers, stored as sorted ranges that neither overlap nor touch each other.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RangeSet {
+    ranges: Vec<RangeInclusive<i64>>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds all values in `range`, merging it with any overlapping or adjacent ranges.
+    pub fn insert(&mut self, range: RangeInclusive<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = range.into_inner();
+        // the ranges before `first` end before `start - 1`, the ones from `last` on start after
+        // `end + 1`, everything in between touches the new range
+        let first = self
+            .ranges
+            .partition_point(|range| *range.end() < start.saturating_sub(1));
+        let last = self
+            .ranges
+            .partition_point(|range| *range.start() <= end.saturating_add(1));
+        if first < last {
+            start = start.min(*self.ranges[first].start());
+            end = end.max(*self.ranges[last - 1].end());
+        }
+        self.ranges.splice(first..last, [start..=end]);
+    }
+
+    /// Returns all values that are in `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    /// Returns all values that are in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut left, mut right) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            let overlap = *a.start().max(b.start())..=*a.end().min(b.end());
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // the range that ends first cannot overlap with anything else
+            match a.end() < b.end() {
+                true => left.next(),
+                false => right.next(),
+            };
+        }
+        Self { ranges }
+    }
+
+    /// Returns all values that are in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => {
+                self.intersection(&other.complement(*first.start()..=*last.end()))
+            }
+            _ => Self::new(),
+        }
+    }
+
+    /// Returns all values within `bounds` that are not in `self`.
+    pub fn complement(&self, bounds: RangeInclusive<i64>) -> Self {
+        let mut ranges = vec![];
+        let mut next = Some(*bounds.start());
+        for range in &self.ranges {
+            let Some(start) = next else {
+                break;
+            };
+            if *range.start() > start {
+                ranges.push(start..=(*range.start() - 1).min(*bounds.end()));
+            }
+            // nothing is left after a range that ends at `i64::MAX`
+            next = range.end().checked_add(1).map(|after| after.max(start));
+        }
+        if let Some(start) = next {
+            ranges.push(start..=*bounds.end());
+        }
+        ranges.retain(|range| !range.is_empty());
+        Self { ranges }
+    }
+
+    /// The number of values in the set.
+    ///
+    /// Panics if the set contains all of `i64`, as its 2^64 values do not fit into `u64`.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .try_fold(0u64, |len, range| {
+                len.checked_add(range.end().abs_diff(*range.start()))?
+                    .checked_add(1)
+            })
+            .expect("the set has more values than fit into `u64`")
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl From<RangeInclusive<i64>> for RangeSet {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<RangeInclusive<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<i64>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i64>]) -> RangeSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn insert_merges() {
+        let mut ranges = set(&[10..=12, 1..=3, 20..=25, 5..=5]);
+        assert_eq!(ranges, set(&[1..=3, 5..=5, 10..=12, 20..=25]));
+        ranges.insert(4..=11);
+        ranges.insert(8..=7);
+        assert_eq!(ranges.ranges, [1..=12, 20..=25]);
+        assert_eq!(ranges.len(), 18);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn set_operations() {
+        let a = set(&[0..=10, 20..=30]);
+        let b = set(&[5..=24, 40..=40]);
+        assert_eq!(a.union(&b).ranges, [0..=30, 40..=40]);
+        assert_eq!(a.intersection(&b).ranges, [5..=10, 20..=24]);
+        assert_eq!(a.difference(&b).ranges, [0..=4, 25..=30]);
+        assert_eq!(b.difference(&a).ranges, [11..=19, 40..=40]);
+        assert_eq!(a.complement(-5..=35).ranges, [-5..=-1, 11..=19, 31..=35]);
+        assert!(RangeSet::new().complement(3..=2).is_empty());
+        assert!(a.intersection(&set(&[11..=19])).is_empty());
+    }
+
+    #[test]
+    fn handles_the_ends_of_the_domain() {
+        let all = i64::MIN..=i64::MAX;
+        assert_eq!(
+            set(&[0..=i64::MAX]).complement(all.clone()).ranges,
+            [i64::MIN..=-1]
+        );
+        assert_eq!(
+            set(&[i64::MIN..=0]).complement(all.clone()).ranges,
+            [1..=i64::MAX]
+        );
+        assert!(RangeSet::from(all.clone())
+            .complement(all.clone())
+            .is_empty());
+        assert_eq!(set(&[0..=0]).complement(all.clone()).len(), u64::MAX);
+        assert_eq!(set(&[i64::MAX..=i64::MAX]).len(), 1);
+    }
+
+    #[test]
+    #[should_panic = "more values than fit into `u64`"]
+    fn len_of_all_values_panics() {
+        set(&[i64::MIN..=i64::MAX]).len();
+    }
+}
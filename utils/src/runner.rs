@@ -0,0 +1,432 @@
+use std::{
+    any::Any,
+    borrow::Cow,
+    cell::{Cell, RefCell},
+    fmt::{self, Display, Formatter},
+    panic::{self, PanicHookInfo},
+    sync::{mpsc, Once},
+    thread,
+    time::{Duration, Instant},
+};
+
+use serde_json::{json, Value};
+
+use crate::{
+    allocations,
+    answer::Answer,
+    cache::{self, Cache},
+    cancel::{self, Token},
+    config::Config,
+    term::{self, Style},
+    timing::{self, Phases},
+};
+
+/// The stack size of the threads running the days, as some of them recurse deeply.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub name: &'static str,
+    pub title: &'static str,
+    /// The puzzle input embedded at compile time
+    pub input: &'static str,
+    pub solve: &'static (dyn Fn(&str) -> [Answer; 2] + Sync),
+    /// Identifies the build of a day whose code is not part of the runner, like a plugin, see
+    /// [`crate::cache::file_id`]. `None` for the days built into the runner.
+    pub build_id: Option<u64>,
+}
+
+/// Why a day did not finish.
+pub struct Failure {
+    message: String,
+    location: Option<String>,
+}
+
+impl Failure {
+    /// Where the solver panicked, if it is known.
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(location) = &self.location {
+            write!(f, " at {location}")?;
+        }
+        Ok(())
+    }
+}
+
+thread_local! {
+    /// The location of the last panic on this thread, recorded by the panic hook.
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// How many calls of [`isolated`] this thread is inside of.
+    static ISOLATED: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Replaces the panic output inside [`isolated`] with recording the location, so that the runner
+/// can report those panics itself. Other panics still go to the previous hook.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info: &PanicHookInfo| {
+            if ISOLATED.get() == 0 {
+                return previous(info);
+            }
+            let location = info.location().map(|loc| loc.to_string());
+            PANIC_LOCATION.with(|cell| *cell.borrow_mut() = location);
+        }))
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message.to_string(),
+        (_, Some(message)) => message.clone(),
+        _ => "Box<dyn Any>".to_owned(),
+    }
+}
+
+/// Runs `f`, catching any panic it raises.
+pub fn isolated<T>(f: impl FnOnce() -> T) -> Result<T, Failure> {
+    install_panic_hook();
+    ISOLATED.set(ISOLATED.get() + 1);
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    ISOLATED.set(ISOLATED.get() - 1);
+    result.map_err(|payload| Failure {
+        message: panic_message(payload.as_ref()),
+        location: PANIC_LOCATION.with(|cell| cell.borrow_mut().take()),
+    })
+}
+
+/// How a day run ended.
+pub enum Outcome<T> {
+    Finished(T),
+    Failed(Failure),
+    TimedOut(Duration),
+}
+
+/// Runs `f` on a separate thread, cancelling it once `timeout` is exceeded.
+///
+/// A day that does not reach a [`cancel::checkpoint`] after being cancelled is left running in the
+/// background.
+pub fn run_with_timeout<T: Send + 'static>(
+    f: impl FnOnce() -> T + Send + 'static,
+    timeout: Option<Duration>,
+) -> Outcome<T> {
+    let token = Token::default();
+    let (tx, rx) = mpsc::channel();
+    let worker_token = token.clone();
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            cancel::set_current(worker_token);
+            // the receiver is gone if the day timed out
+            let _ = tx.send(isolated(f));
+        })
+        .expect("failed to spawn day thread");
+
+    let result = match timeout {
+        Some(timeout) => rx.recv_timeout(timeout).map_err(|_| timeout),
+        None => Ok(rx.recv().expect("day thread did not report back")),
+    };
+    match result {
+        Ok(Ok(value)) => Outcome::Finished(value),
+        Ok(Err(failure)) => Outcome::Failed(failure),
+        Err(timeout) => {
+            token.cancel();
+            Outcome::TimedOut(timeout)
+        }
+    }
+}
+
+/// The answers of a day and what it took to find them.
+pub struct Solution {
+    pub answers: [Answer; 2],
+    /// The mean time of each phase
+    pub phases: Phases,
+    /// The mean number of allocations
+    pub allocations: u64,
+}
+
+/// Solves `day` `repeat` times on a separate thread, returning the answers of the last run.
+fn solve_repeatedly(
+    day: &Day,
+    input: Cow<'static, str>,
+    repeat: u32,
+    timeout: Option<Duration>,
+) -> Outcome<Solution> {
+    let solve = day.solve;
+    run_with_timeout(
+        move || {
+            let mut phases = Phases::default();
+            let mut allocations = 0;
+            for _ in 1..repeat {
+                let ((_, run_phases), run_allocations) =
+                    allocations::count(|| timing::measure(|| solve(&input)));
+                phases += run_phases;
+                allocations += run_allocations;
+            }
+            let ((answers, run_phases), run_allocations) =
+                allocations::count(|| timing::measure(|| solve(&input)));
+            Solution {
+                answers,
+                phases: (phases + run_phases) / repeat,
+                allocations: (allocations + run_allocations) / repeat as u64,
+            }
+        },
+        timeout,
+    )
+}
+
+/// Reads the input of `day` and solves it `config.repeat` times, stopping after `timeout`.
+///
+/// An input that cannot be read is reported as a failure.
+pub fn solve(day: &Day, config: &Config, timeout: Option<Duration>) -> Outcome<Solution> {
+    match config.input(day) {
+        Ok(input) => solve_repeatedly(day, input, config.repeat.max(1), timeout),
+        Err(message) => Outcome::Failed(Failure {
+            message,
+            location: None,
+        }),
+    }
+}
+
+/// Runs all `days` one after the other, reporting the failing ones without stopping.
+///
+/// Each day is solved `config.repeat` times and stopped after `timeout`, if one is given, unless
+/// its answers are in the `cache`. Finally the time spent in each phase of the days that were
+/// solved is shown. Returns whether all days succeeded.
+pub fn run(
+    days: &[Day],
+    config: &Config,
+    timeout: Option<Duration>,
+    cache: Option<&Cache>,
+) -> bool {
+    let color = term::color();
+    let repeat = config.repeat.max(1);
+    let mut failed = vec![];
+    let mut breakdown = vec![];
+    for day in days {
+        println!("--- {} ---", day.title);
+        let input = match config.input(day) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{}: {err}\n", Style::BOLD_RED.paint(color, "FAILED"));
+                failed.push(format!("{} (failed)", day.name));
+                continue;
+            }
+        };
+        if let Some([part1, part2]) = cache.and_then(|cache| cache.get(day, &input, config)) {
+            println!("Part 1: {}", part1.render(color));
+            println!("Part 2: {}", part2.render(color));
+            println!("{}\n", Style::GRAY.paint(color, "cached"));
+            continue;
+        }
+        let start = Instant::now();
+        match solve_repeatedly(day, input.clone(), repeat, timeout) {
+            Outcome::Finished(Solution {
+                answers, phases, ..
+            }) => {
+                println!("Part 1: {}", answers[0].render(color));
+                println!("Part 2: {}", answers[1].render(color));
+                breakdown.push((day.title, phases));
+                if let Some(Err(err)) =
+                    cache.map(|cache| cache.insert(day, &input, config, &answers))
+                {
+                    eprintln!("could not cache the answers: {err}");
+                }
+            }
+            Outcome::Failed(failure) => {
+                println!("{}: {failure}", Style::BOLD_RED.paint(color, "FAILED"));
+                failed.push(format!("{} (failed)", day.name));
+            }
+            Outcome::TimedOut(timeout) => {
+                println!(
+                    "{}: exceeded {timeout:?}",
+                    Style::BOLD_YELLOW.paint(color, "TIMEOUT")
+                );
+                failed.push(format!("{} (timeout)", day.name));
+            }
+        }
+        let elapsed = match repeat {
+            1 => format!("{:?}", start.elapsed()),
+            _ => format!("{:?} (mean of {repeat} runs)", start.elapsed() / repeat),
+        };
+        println!("{}\n", Style::GRAY.paint(color, elapsed));
+    }
+    print_breakdown(&breakdown, color);
+    if !failed.is_empty() {
+        println!(
+            "{} {}",
+            Style::BOLD_RED.paint(
+                color,
+                format!("{} of {} days did not finish:", failed.len(), days.len())
+            ),
+            failed.join(", ")
+        );
+    }
+    failed.is_empty()
+}
+
+/// Prints a table of how long each phase of the days took, with the sum over all days below.
+fn print_breakdown(breakdown: &[(&str, Phases)], color: bool) {
+    let width = breakdown
+        .iter()
+        .map(|(title, _)| title.len())
+        .chain(["Total".len()])
+        .max()
+        .unwrap_or_default();
+    let row = |title: &str, cells: [String; 5]| {
+        let cells: Vec<_> = cells.iter().map(|cell| format!("{cell:>10}")).collect();
+        format!("{title:<width$}{}", cells.concat())
+    };
+    let durations = |phases: &Phases| {
+        [
+            phases.parse,
+            phases.part1,
+            phases.part2,
+            phases.other(),
+            phases.total,
+        ]
+        .map(|duration| format!("{duration:.1?}"))
+    };
+
+    let header = ["Parse", "Part 1", "Part 2", "Other", "Total"].map(str::to_owned);
+    println!("{}", Style::BOLD.paint(color, row("", header)));
+    let mut total = Phases::default();
+    for (title, phases) in breakdown {
+        println!("{}", row(title, durations(phases)));
+        total += *phases;
+    }
+    println!(
+        "{}",
+        Style::BOLD.paint(color, row("Total", durations(&total)))
+    );
+}
+
+/// Like [`run`], but prints one JSON object per day instead, for tools like `watch`.
+///
+/// Each object has the `name` and `title` of the day, its `answers` as written by
+/// [`Answer::to_json`], the mean `phases` in nanoseconds and `allocations`, an `error` if it did
+/// not finish, and the [`cache::answer_key`] of its input as `key`.
+pub fn run_json(days: &[Day], config: &Config, timeout: Option<Duration>) -> bool {
+    let mut succeeded = true;
+    for day in days {
+        let (answers, phases, allocations, error) = match solve(day, config, timeout) {
+            Outcome::Finished(solution) => (
+                json!(solution.answers.map(|answer| answer.to_json())),
+                solution.phases,
+                Some(solution.allocations),
+                None,
+            ),
+            Outcome::Failed(failure) => (
+                Value::Null,
+                Phases::default(),
+                None,
+                Some(failure.to_string()),
+            ),
+            Outcome::TimedOut(timeout) => (
+                Value::Null,
+                Phases::default(),
+                None,
+                Some(format!("exceeded {timeout:?}")),
+            ),
+        };
+        succeeded &= error.is_none();
+        let key = config
+            .input(day)
+            .ok()
+            .map(|input| format!("{:016x}", cache::answer_key(day, &input, config)));
+        println!(
+            "{}",
+            json!({
+                "name": day.name,
+                "title": day.title,
+                "answers": answers,
+                "phases": phases.to_json(),
+                "allocations": allocations,
+                "error": error,
+                "key": key,
+            })
+        );
+    }
+    succeeded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn day(name: &'static str, solve: &'static (dyn Fn(&str) -> [Answer; 2] + Sync)) -> Day {
+        Day {
+            name,
+            title: name,
+            input: "",
+            solve,
+            build_id: None,
+        }
+    }
+
+    #[test]
+    fn isolated_reports_message_and_location() {
+        let failure = isolated(|| panic!("no answer")).err().unwrap();
+        let location = failure.location().unwrap();
+        assert!(location.starts_with("utils/src/runner.rs:"), "{location}");
+        assert_eq!(failure.to_string(), format!("no answer at {location}"));
+        assert_eq!(isolated(|| 7).ok(), Some(7));
+    }
+
+    #[test]
+    fn run_continues_after_a_failing_day() {
+        static SOLVED: AtomicBool = AtomicBool::new(false);
+        let days = [
+            day("day1", &|_| panic!("no answer")),
+            day("day2", &|_| {
+                SOLVED.store(true, Ordering::SeqCst);
+                [Answer::Integer(1), Answer::Integer(2)]
+            }),
+        ];
+        assert!(!run(&days, &Config::default(), None, None));
+        assert!(SOLVED.load(Ordering::SeqCst));
+        assert!(run(&days[1..], &Config::default(), None, None));
+    }
+
+    /// Sets its flag when dropped, which happens when the day unwinds.
+    struct Stopped(&'static AtomicBool);
+
+    impl Drop for Stopped {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn cancels_days_at_checkpoints_after_the_timeout() {
+        static STOPPED: AtomicBool = AtomicBool::new(false);
+        let outcome = run_with_timeout(
+            || {
+                let _stopped = Stopped(&STOPPED);
+                loop {
+                    cancel::checkpoint();
+                    thread::sleep(Duration::from_millis(1));
+                }
+            },
+            Some(Duration::from_millis(20)),
+        );
+        assert!(matches!(outcome, Outcome::TimedOut(timeout) if timeout.as_millis() == 20));
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !STOPPED.load(Ordering::SeqCst) {
+            assert!(Instant::now() < deadline, "the day did not stop");
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// The nodes reached by a search, with their distance from the closest start node and the node
+/// they were reached from.
+#[derive(Debug, Clone)]
+pub struct SearchTree<N> {
+    nodes: HashMap<N, (u64, Option<N>)>,
+}
+
+impl<N: Copy + Eq + Hash> SearchTree<N> {
+    fn new(starts: impl IntoIterator<Item = N>) -> Self {
+        Self {
+            nodes: starts.into_iter().map(|start| (start, (0, None))).collect(),
+        }
+    }
+
+    pub fn distance(&self, node: N) -> Option<u64> {
+        self.nodes.get(&node).map(|(distance, _)| *distance)
+    }
+
+    /// Returns the node `node` was reached from, or `None` for start and unreached nodes.
+    pub fn predecessor(&self, node: N) -> Option<N> {
+        self.nodes
+            .get(&node)
+            .and_then(|(_, predecessor)| *predecessor)
+    }
+
+    /// Iterates over all reached nodes and their distances in no particular order.
+    pub fn distances(&self) -> impl Iterator<Item = (N, u64)> + '_ {
+        self.nodes
+            .iter()
+            .map(|(node, (distance, _))| (*node, *distance))
+    }
+
+    /// Reconstructs the path from a start node to `node`, including both ends.
+    pub fn path_to(&self, node: N) -> Option<Vec<N>> {
+        self.nodes.get(&node)?;
+        let mut path = vec![node];
+        while let Some(predecessor) = self.predecessor(*path.last().unwrap()) {
+            path.push(predecessor);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+//////////////////////////////////
+
+/// A breadth-first search, yielding each node with its distance in the order they are reached.
+///
+/// All edges have a cost of 1. Use [`dijkstra`] or [`astar`] for weighted edges.
+pub struct Bfs<N, F> {
+    queue: VecDeque<N>,
+    tree: SearchTree<N>,
+    neighbors: F,
+}
+
+/// Starts a breadth-first search from all `starts` at once.
+pub fn bfs<N, F, I>(starts: impl IntoIterator<Item = N>, neighbors: F) -> Bfs<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let tree = SearchTree::new(starts);
+    Bfs {
+        queue: tree.nodes.keys().copied().collect(),
+        tree,
+        neighbors,
+    }
+}
+
+impl<N, F> Bfs<N, F> {
+    pub fn tree(&self) -> &SearchTree<N> {
+        &self.tree
+    }
+}
+
+impl<N, F, I> Bfs<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    /// Runs the search to completion and returns the tree of all reachable nodes.
+    pub fn into_tree(mut self) -> SearchTree<N> {
+        self.by_ref().for_each(drop);
+        self.tree
+    }
+}
+
+impl<N, F, I> Iterator for Bfs<N, F>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Item = (N, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.queue.pop_front()?;
+        let distance = self.tree.distance(node).unwrap();
+        for neighbor in (self.neighbors)(node) {
+            if let Entry::Vacant(entry) = self.tree.nodes.entry(neighbor) {
+                entry.insert((distance + 1, Some(node)));
+                self.queue.push_back(neighbor);
+            }
+        }
+        Some((node, distance))
+    }
+}
+
+//////////////////////////////////
+
+/// A best-first search over weighted edges, yielding each node with its final distance in the
+/// order of increasing estimated total cost.
+pub struct BestFirst<N, F, H> {
+    heap: BinaryHeap<Reverse<(u64, N)>>,
+    tree: SearchTree<N>,
+    neighbors: F,
+    heuristic: H,
+}
+
+/// Starts Dijkstra's algorithm from all `starts` at once. `neighbors` returns the reachable nodes
+/// together with the cost of getting there.
+pub fn dijkstra<N, F, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: F,
+) -> BestFirst<N, F, fn(N) -> u64>
+where
+    N: Copy + Ord + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(starts, neighbors, |_| 0)
+}
+
+/// Starts an A* search from all `starts` at once. The `heuristic` must never overestimate the
+/// remaining cost to the goal, otherwise the yielded distances may be too high.
+pub fn astar<N, F, H, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: F,
+    mut heuristic: H,
+) -> BestFirst<N, F, H>
+where
+    N: Copy + Ord + Hash,
+    F: FnMut(N) -> I,
+    H: FnMut(N) -> u64,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let tree = SearchTree::new(starts);
+    BestFirst {
+        heap: tree
+            .nodes
+            .keys()
+            .map(|start| Reverse((heuristic(*start), *start)))
+            .collect(),
+        tree,
+        neighbors,
+        heuristic,
+    }
+}
+
+impl<N, F, H> BestFirst<N, F, H> {
+    pub fn tree(&self) -> &SearchTree<N> {
+        &self.tree
+    }
+}
+
+impl<N, F, H, I> Iterator for BestFirst<N, F, H>
+where
+    N: Copy + Ord + Hash,
+    F: FnMut(N) -> I,
+    H: FnMut(N) -> u64,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    type Item = (N, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Reverse((estimate, node)) = self.heap.pop()?;
+            let distance = self.tree.distance(node).unwrap();
+            // skip entries that were superseded by a shorter path
+            if estimate != distance + (self.heuristic)(node) {
+                continue;
+            }
+            for (neighbor, cost) in (self.neighbors)(node) {
+                let new_distance = distance + cost;
+                match self.tree.nodes.entry(neighbor) {
+                    Entry::Occupied(entry) if entry.get().0 <= new_distance => continue,
+                    Entry::Occupied(mut entry) => {
+                        entry.insert((new_distance, Some(node)));
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert((new_distance, Some(node)));
+                    }
+                }
+                self.heap.push(Reverse((
+                    new_distance + (self.heuristic)(neighbor),
+                    neighbor,
+                )));
+            }
+            return Some((node, distance));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A line of nodes `0..10` where stepping by 1 costs 3 and jumping by 2 costs 5.
+    fn line(node: i32) -> Vec<(i32, u64)> {
+        [(node + 1, 3), (node + 2, 5), (node - 1, 3)]
+            .into_iter()
+            .filter(|(next, _)| (0..10).contains(next))
+            .collect()
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let mut search = bfs([0], |node| line(node).into_iter().map(|(next, _)| next));
+        assert_eq!(search.find(|(node, _)| *node == 9), Some((9, 5)));
+        assert_eq!(search.tree().path_to(9).unwrap(), [0, 1, 3, 5, 7, 9]);
+    }
+
+    #[test]
+    fn multi_source() {
+        let tree = bfs([0, 9], |node| line(node).into_iter().map(|(next, _)| next)).into_tree();
+        assert_eq!(tree.distance(5), Some(3));
+        assert_eq!(
+            tree.distances().map(|(_, distance)| distance).max(),
+            Some(3)
+        );
+        assert_eq!(tree.predecessor(0), None);
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        let mut dijkstra = dijkstra([0], line);
+        let mut astar = astar([0], line, |node| (9 - node as u64) * 5 / 2);
+        assert_eq!(dijkstra.find(|(node, _)| *node == 9), Some((9, 23)));
+        assert_eq!(astar.find(|(node, _)| *node == 9), Some((9, 23)));
+        assert_eq!(dijkstra.tree().path_to(9).unwrap().len(), 6);
+        assert_eq!(astar.tree().path_to(9).unwrap().len(), 6);
+    }
+}
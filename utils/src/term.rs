@@ -0,0 +1,122 @@
+use std::{
+    env,
+    fmt::Display,
+    io::{self, IsTerminal, Write},
+    sync::OnceLock,
+};
+
+use clap::ValueEnum;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorMode {
+    /// Color the output if it is a terminal and `NO_COLOR` is not set
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorMode {
+    /// Whether to color output written to a stream, given whether that stream is a terminal.
+    pub fn enabled(self, terminal: bool) -> bool {
+        match self {
+            ColorMode::Auto => terminal && env::var_os("NO_COLOR").is_none_or(|var| var.is_empty()),
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+        }
+    }
+}
+
+static COLOR: OnceLock<bool> = OnceLock::new();
+
+/// Decides whether stdout is colored. Only the first call has an effect.
+pub fn init(mode: ColorMode) {
+    let _ = COLOR.set(mode.enabled(io::stdout().is_terminal()));
+}
+
+/// Whether stdout is colored, as decided by [`init`] or [`ColorMode::Auto`] if it was not called.
+pub fn color() -> bool {
+    *COLOR.get_or_init(|| ColorMode::Auto.enabled(io::stdout().is_terminal()))
+}
+
+/// An SGR escape sequence, see <https://en.wikipedia.org/wiki/ANSI_escape_code#SGR>.
+#[derive(Debug, Clone, Copy)]
+pub struct Style(&'static str);
+
+impl Style {
+    pub const BOLD: Style = Style("1");
+    pub const BOLD_RED: Style = Style("1;31");
+    pub const BOLD_GREEN: Style = Style("1;32");
+    pub const BOLD_YELLOW: Style = Style("1;33");
+    pub const BLACK: Style = Style("30");
+    pub const GRAY: Style = Style("90");
+    pub const BG_RED: Style = Style("41");
+    pub const BG_GREEN: Style = Style("42");
+    pub const BG_YELLOW: Style = Style("43");
+    pub const BG_CYAN: Style = Style("46");
+    pub const BG_WHITE: Style = Style("47");
+
+    /// Wraps `text` in this style if `color` is enabled.
+    pub fn paint(self, color: bool, text: impl Display) -> String {
+        match color {
+            true => format!("\x1b[{}m{text}\x1b[0m", self.0),
+            false => text.to_string(),
+        }
+    }
+}
+
+/// Picks the colored or the plain-ASCII variant of a tile.
+///
+/// Colored tiles are usually a blank cell painted with `style`, their plain counterparts a
+/// character that stands out without color.
+pub fn glyph(color: bool, style: Style, colored: &str, plain: &str) -> String {
+    match color {
+        true => style.paint(true, colored),
+        false => plain.to_owned(),
+    }
+}
+
+/// Clears the screen and hides the cursor before the first frame of an animation.
+///
+/// The debug visualizations only move the cursor if stdout is a terminal, otherwise the frames
+/// are simply written one after the other.
+pub fn start_animation() {
+    if io::stdout().is_terminal() {
+        print!("\x1b[2J\x1b[?25l");
+    }
+}
+
+/// Moves the cursor to the top left corner, so the next frame overwrites the previous one.
+pub fn next_frame(out: &mut impl Write) -> io::Result<()> {
+    match io::stdout().is_terminal() {
+        true => write!(out, "\x1b[H"),
+        false => writeln!(out),
+    }
+}
+
+/// Shows the cursor again after the last frame of an animation.
+pub fn end_animation() {
+    if io::stdout().is_terminal() {
+        print!("\x1b[?25h");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_modes_ignore_the_terminal() {
+        assert!(ColorMode::Always.enabled(false));
+        assert!(!ColorMode::Never.enabled(true));
+        assert!(!ColorMode::Auto.enabled(false));
+    }
+
+    #[test]
+    fn plain_glyphs_are_ascii() {
+        assert_eq!(Style::BOLD.paint(true, 1), "\x1b[1m1\x1b[0m");
+        assert_eq!(Style::BOLD.paint(false, 1), "1");
+        assert_eq!(glyph(false, Style::BG_GREEN, " ", "#"), "#");
+    }
+}
@@ -0,0 +1,163 @@
+use std::{
+    cell::RefCell,
+    ops::{Add, AddAssign, Div},
+    time::{Duration, Instant},
+};
+
+use serde_json::{json, Value};
+use tracing::{span, Subscriber};
+use tracing_subscriber::{layer::Context, registry::LookupSpan};
+
+/// How long the phases of solving a day took, as recorded by [`PhaseLayer`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Phases {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+    /// The whole run, including the time outside of the phases above
+    pub total: Duration,
+}
+
+impl Phases {
+    /// The time spent outside of the named phases, e.g. in the `search` of `day12_bfs`.
+    pub fn other(&self) -> Duration {
+        self.total
+            .saturating_sub(self.parse + self.part1 + self.part2)
+    }
+
+    pub fn to_json(self) -> Value {
+        json!({
+            "parse_ns": self.parse.as_nanos() as u64,
+            "part1_ns": self.part1.as_nanos() as u64,
+            "part2_ns": self.part2.as_nanos() as u64,
+            "total_ns": self.total.as_nanos() as u64,
+        })
+    }
+
+    /// Reads phases written by [`Phases::to_json`], treating missing ones as zero.
+    pub fn from_json(value: &Value) -> Phases {
+        let nanos = |key| Duration::from_nanos(value[key].as_u64().unwrap_or_default());
+        Phases {
+            parse: nanos("parse_ns"),
+            part1: nanos("part1_ns"),
+            part2: nanos("part2_ns"),
+            total: nanos("total_ns"),
+        }
+    }
+}
+
+impl AddAssign for Phases {
+    fn add_assign(&mut self, rhs: Self) {
+        self.parse += rhs.parse;
+        self.part1 += rhs.part1;
+        self.part2 += rhs.part2;
+        self.total += rhs.total;
+    }
+}
+
+impl Add for Phases {
+    type Output = Phases;
+
+    fn add(mut self, rhs: Self) -> Phases {
+        self += rhs;
+        self
+    }
+}
+
+impl Div<u32> for Phases {
+    type Output = Phases;
+
+    fn div(self, rhs: u32) -> Phases {
+        Phases {
+            parse: self.parse / rhs,
+            part1: self.part1 / rhs,
+            part2: self.part2 / rhs,
+            total: self.total / rhs,
+        }
+    }
+}
+
+thread_local! {
+    /// The phases of the [`measure`] call running on this thread, if any.
+    static PHASES: RefCell<Option<Phases>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, timing the `parse`, `part1` and `part2` spans it enters on this thread.
+///
+/// The spans are only seen if [`PhaseLayer`] is installed, otherwise just the total is measured.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Phases) {
+    PHASES.with(|phases| *phases.borrow_mut() = Some(Phases::default()));
+    let start = Instant::now();
+    let value = f();
+    let total = start.elapsed();
+    let phases = PHASES
+        .with(|phases| phases.borrow_mut().take())
+        .unwrap_or_default();
+    (value, Phases { total, ..phases })
+}
+
+/// Adds the time spent in top-level spans to the [`measure`] call on the same thread.
+pub struct PhaseLayer;
+
+/// When a span was last entered, stored in its extensions.
+struct Entered(Instant);
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> tracing_subscriber::Layer<S> for PhaseLayer {
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().replace(Entered(Instant::now()));
+        }
+    }
+
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        // nested spans are already part of the phase around them
+        if span.parent().is_some() {
+            return;
+        }
+        let Some(Entered(start)) = span.extensions_mut().remove::<Entered>() else {
+            return;
+        };
+        PHASES.with(|phases| {
+            if let Some(phases) = phases.borrow_mut().as_mut() {
+                match span.name() {
+                    "parse" => phases.parse += start.elapsed(),
+                    "part1" => phases.part1 += start.elapsed(),
+                    "part2" => phases.part2 += start.elapsed(),
+                    _ => {}
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::thread;
+
+    use tracing::info_span;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn times_top_level_spans() {
+        let subscriber = tracing_subscriber::registry().with(PhaseLayer);
+        let (answer, phases) = tracing::subscriber::with_default(subscriber, || {
+            measure(|| {
+                info_span!("parse").in_scope(|| {});
+                info_span!("part2").in_scope(|| {
+                    info_span!("parse").in_scope(|| thread::sleep(Duration::from_millis(20)))
+                });
+                42
+            })
+        });
+        assert_eq!(answer, 42);
+        assert!(phases.parse < Duration::from_millis(20));
+        assert!(phases.part2 >= Duration::from_millis(20));
+        assert_eq!(phases.part1, Duration::ZERO);
+        assert!(phases.total >= phases.parse + phases.part2);
+    }
+}